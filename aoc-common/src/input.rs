@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
+
+/// Where a day's puzzle input should be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The input embedded into the binary at compile time.
+    Embedded,
+    /// Standard input.
+    Stdin,
+    /// A file on disk.
+    Path(PathBuf),
+}
+
+impl Source {
+    /// Interprets a command line argument as an input source. `-` means stdin, any other value is
+    /// a path, and no argument at all means the embedded input.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => Source::Embedded,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.into()),
+        }
+    }
+
+    /// Reads the input from this source, using `embedded` for [`Source::Embedded`].
+    ///
+    /// Trailing line endings are stripped, so that inputs saved with a final newline (as they are
+    /// when downloaded, or by most editors) are read identically to the committed ones.
+    pub fn read(&self, embedded: &'static str) -> io::Result<Cow<'static, str>> {
+        let input = match self {
+            Source::Embedded => return Ok(Cow::Borrowed(trim(embedded))),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            Source::Path(path) => std::fs::read_to_string(path)?,
+        };
+        let len = trim(&input).len();
+        let mut input = input;
+        input.truncate(len);
+        Ok(Cow::Owned(input))
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Embedded => write!(f, "embedded input"),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Reads the input for a day's binary from the source named by its first command line argument,
+/// falling back to `embedded`. Exits the process if the input can't be read.
+pub fn from_args(embedded: &'static str) -> Cow<'static, str> {
+    let arg = std::env::args().nth(1);
+    let source = Source::from_arg(arg.as_deref());
+    source.read(embedded).unwrap_or_else(|err| {
+        eprintln!("error: failed to read {source}: {err}");
+        process::exit(1);
+    })
+}
+
+fn trim(input: &str) -> &str {
+    input.trim_end_matches(['\n', '\r'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_arg() {
+        assert_eq!(Source::from_arg(None), Source::Embedded);
+        assert_eq!(Source::from_arg(Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("input.txt")),
+            Source::Path("input.txt".into())
+        );
+    }
+
+    #[test]
+    fn read_embedded() {
+        assert_eq!(Source::Embedded.read("1\n2\r\n\n").unwrap(), "1\n2");
+    }
+
+    #[test]
+    fn read_path() {
+        let path = std::env::temp_dir().join("aoc-common-read-path.txt");
+        std::fs::write(&path, "a\nb\n").unwrap();
+        assert_eq!(Source::Path(path.clone()).read("").unwrap(), "a\nb");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn read_missing_path() {
+        let source = Source::Path("does/not/exist.txt".into());
+        assert!(source.read("").is_err());
+    }
+}
@@ -0,0 +1,4 @@
+//! Utilities shared between each day's solutions and the `aoc` runner.
+
+pub mod input;
+pub mod parse;
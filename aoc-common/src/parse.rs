@@ -0,0 +1,262 @@
+//! Errors for malformed puzzle input, located by the line and column they were found at.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// An error found while parsing a day's puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line the offending text starts on, counting from 1.
+    pub line: usize,
+    /// The column the offending text starts at, counting characters from 1.
+    pub column: usize,
+    /// The offending text. This is empty if something was missing from the end of a line, or of
+    /// the input.
+    pub text: String,
+    /// What was wrong with the text, such as "expected a number".
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error about `span`, locating it by where it lies in `input`.
+    ///
+    /// # Panics
+    ///
+    /// If `span` isn't a slice of `input`.
+    pub fn new<S>(input: &S, span: &S, message: impl Into<String>) -> Self
+    where
+        S: AsRef<[u8]> + ?Sized,
+    {
+        let (input, span) = (input.as_ref(), span.as_ref());
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + span.len() <= input.len())
+            .expect("span should be a slice of the input");
+        let before = &input[..offset];
+        let line_start = before
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |idx| idx + 1);
+        let column = String::from_utf8_lossy(&before[line_start..])
+            .chars()
+            .count()
+            + 1;
+        Self {
+            line: before.iter().filter(|&&b| b == b'\n').count() + 1,
+            column,
+            text: String::from_utf8_lossy(span).into_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error about something missing from the end of `input`.
+    pub fn end_of_input<S>(input: &S, message: impl Into<String>) -> Self
+    where
+        S: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+        Self::new(input, &input[input.len()..], message)
+    }
+
+    /// Relocates an error found while parsing `s` on its own to where `s` lies in `input`.
+    pub fn relocate<S>(self, input: &S, s: &S) -> Self
+    where
+        S: AsRef<[u8]> + ?Sized,
+    {
+        let s = s.as_ref();
+        let start = Self::new(input.as_ref(), &s[..0], "");
+        Self {
+            line: start.line + self.line - 1,
+            column: if self.line == 1 {
+                start.column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+
+    /// Renders this error as a diagnostic for `input`, which was read from `source`: the location
+    /// and message, followed by the offending line with the text underlined.
+    pub fn render(&self, input: &str, source: impl Display) -> String {
+        let line = input.lines().nth(self.line - 1).unwrap_or("");
+        let gutter = " ".repeat(self.line.to_string().len());
+        let indent = " ".repeat(self.column - 1);
+        let text = self.text.lines().next().unwrap_or("");
+        let underline = "^".repeat(text.chars().count().max(1));
+        format!(
+            "{source}:{}:{}: {}\n{gutter} |\n{} | {line}\n{gutter} | {indent}{underline}",
+            self.line,
+            self.column,
+            self.found(),
+            self.line,
+        )
+    }
+
+    fn found(&self) -> String {
+        if self.text.is_empty() {
+            format!("{}, found nothing", self.message)
+        } else {
+            format!("{}, found `{}`", self.message, self.text)
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.found()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Splits `s`, a slice of `input`, around the first occurrence of `delimiter`.
+pub fn split_once<'a>(
+    input: &str,
+    s: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(input, s, format!("expected `{delimiter}`")))
+}
+
+/// Strips `prefix` from the start of `s`, a slice of `input`.
+pub fn strip_prefix<'a>(input: &str, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        let end = s.len().min(prefix.len());
+        let end = (end..=s.len())
+            .find(|&end| s.is_char_boundary(end))
+            .unwrap();
+        ParseError::new(input, &s[..end], format!("expected `{prefix}`"))
+    })
+}
+
+/// Strips `suffix` from the end of `s`, a slice of `input`.
+pub fn strip_suffix<'a>(input: &str, s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    s.strip_suffix(suffix).ok_or_else(|| {
+        let start = s.len().saturating_sub(suffix.len());
+        let start = (0..=start)
+            .rev()
+            .find(|&start| s.is_char_boundary(start))
+            .unwrap();
+        ParseError::new(input, &s[start..], format!("expected `{suffix}`"))
+    })
+}
+
+/// Converts each character of `s`, a slice of `input`, with `f`, which returns `None` for any
+/// character that isn't one of the `expected` ones.
+pub fn chars<T, C, F>(input: &str, s: &str, expected: &str, mut f: F) -> Result<C, ParseError>
+where
+    C: FromIterator<T>,
+    F: FnMut(char) -> Option<T>,
+{
+    s.char_indices()
+        .map(|(idx, c)| {
+            f(c).ok_or_else(|| {
+                let c = &s[idx..idx + c.len_utf8()];
+                ParseError::new(input, c, format!("expected {expected}"))
+            })
+        })
+        .collect()
+}
+
+/// Parses `s`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, s: &str) -> Result<T, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::new(input, s, "expected a number"))
+}
+
+/// Parses each whitespace separated word of `s`, a slice of `input`, as a number.
+pub fn numbers<T: FromStr>(input: &str, s: &str) -> Result<Vec<T>, ParseError> {
+    s.split_ascii_whitespace()
+        .map(|word| number(input, word))
+        .collect()
+}
+
+/// Prints `err` as a diagnostic for `input`, and exits the process. For use by each day's binary.
+pub fn exit(err: &ParseError, input: &str) -> ! {
+    eprintln!("error: {}", err.render(input, "input"));
+    std::process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Time: 7 15\nDistance: 9 4x";
+
+    #[test]
+    fn locate() {
+        let err = number::<u32>(INPUT, &INPUT[23..]).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                line: 2,
+                column: 13,
+                text: "4x".to_string(),
+                message: "expected a number".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 13: expected a number, found `4x`"
+        );
+    }
+
+    #[test]
+    fn locate_bytes() {
+        let input = "ab\ncd".as_bytes();
+        let err = ParseError::new(input, &input[4..], "expected `e`");
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "d"));
+        let err = ParseError::end_of_input(input, "expected `e`");
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, ""));
+    }
+
+    #[test]
+    fn relocate() {
+        let line = &INPUT[11..];
+        let err = number::<u32>(line, &line[12..]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 13));
+        let err = err.relocate(INPUT, line);
+        assert_eq!((err.line, err.column), (2, 13));
+        let err = ParseError::new("1\n2\n3", &"1\n2\n3"[4..], "").relocate(INPUT, &INPUT[6..]);
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    fn render() {
+        let err = numbers::<u32>(INPUT, &INPUT[21..]).unwrap_err();
+        assert_eq!(
+            err.render(INPUT, "input.txt"),
+            "input.txt:2:13: expected a number, found `4x`
+  |
+2 | Distance: 9 4x
+  |             ^^"
+        );
+    }
+
+    #[test]
+    fn helpers() {
+        let line = &INPUT[..10];
+        assert_eq!(split_once(INPUT, line, ": "), Ok(("Time", "7 15")));
+        assert_eq!(split_once(INPUT, line, "|").unwrap_err().text, line);
+        assert_eq!(strip_prefix(INPUT, line, "Time: "), Ok("7 15"));
+        assert_eq!(strip_prefix(INPUT, line, "Tick").unwrap_err().text, "Time");
+        assert_eq!(strip_suffix(INPUT, line, " 15"), Ok("Time: 7"));
+        assert_eq!(strip_suffix(INPUT, line, "16").unwrap_err().text, "15");
+        assert_eq!(numbers(INPUT, &line[6..]), Ok(vec![7, 15]));
+        let digits = chars::<_, Vec<_>, _>(INPUT, &line[6..], "a digit", |c| c.to_digit(10));
+        assert_eq!(digits.unwrap_err().text, " ");
+        assert_eq!(
+            chars(INPUT, &line[8..], "a digit", |c| c.to_digit(10)),
+            Ok(vec![1, 5])
+        );
+    }
+}
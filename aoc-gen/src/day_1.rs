@@ -0,0 +1,34 @@
+//! Day 1: Trebuchet?!
+
+use rand::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Generates `size` lines of letters, numeric digits and spelled out digits. Every line has at
+/// least one numeric digit, so that both parts can be solved.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            let words = rng.gen_range(1..=8);
+            let digit = rng.gen_range(0..words);
+            for idx in 0..words {
+                if idx == digit {
+                    line.push(rng.gen_range('1'..='9'));
+                }
+                match rng.gen_range(0..4) {
+                    0 => line.push(rng.gen_range('1'..='9')),
+                    1 => line.push_str(WORDS[rng.gen_range(0..WORDS.len())]),
+                    _ => {
+                        let len = rng.gen_range(1..=4);
+                        line.push_str(&crate::name(rng, len))
+                    }
+                }
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
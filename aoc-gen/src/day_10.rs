@@ -0,0 +1,55 @@
+//! Day 10: Pipe Maze
+
+use crate::outline::outline;
+use aoc_grid::geometry::Direction::{self, Down, Left, Right, Up};
+use rand::Rng;
+
+const PIPES: &[u8] = b"|-LJ7F";
+
+/// Generates a `size` by `size` field of pipes, containing a random loop through the starting
+/// tile, `S`, surrounded by random pipes that aren't part of it.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let side = size.max(3);
+    let mut tiles = crate::square_grid(rng, side, |rng| {
+        if rng.gen_bool(0.4) {
+            b'.'
+        } else {
+            PIPES[rng.gen_range(0..PIPES.len())]
+        }
+    });
+    // Draw the loop with thick enough lines that it encloses some tiles, somewhere in the field.
+    let thickness = 2;
+    let nodes = side / (thickness + 1);
+    let steps = outline(rng, nodes, thickness);
+    let slack = side - nodes * (thickness + 1);
+    let mut loop_tiles = Vec::new();
+    let mut pos = (rng.gen_range(0..=slack), rng.gen_range(0..=slack));
+    for (idx, step) in steps.iter().enumerate() {
+        let previous = steps[(idx + steps.len() - 1) % steps.len()];
+        tiles[pos] = pipe(previous.reverse(), *step);
+        loop_tiles.push(pos);
+        pos = tiles.step(pos, *step).unwrap();
+    }
+    let start = loop_tiles[rng.gen_range(0..loop_tiles.len())];
+    tiles[start] = b'S';
+    // Only the loop's pipes may connect to the start, so clear any others next to it.
+    for neighbour in tiles.neighbours4(start).collect::<Vec<_>>() {
+        if !loop_tiles.contains(&neighbour) {
+            tiles[neighbour] = b'.';
+        }
+    }
+    tiles.to_string()
+}
+
+/// The pipe connecting the two directions.
+fn pipe(a: Direction, b: Direction) -> u8 {
+    match (a, b) {
+        (Up, Down) | (Down, Up) => b'|',
+        (Left, Right) | (Right, Left) => b'-',
+        (Up, Right) | (Right, Up) => b'L',
+        (Up, Left) | (Left, Up) => b'J',
+        (Down, Left) | (Left, Down) => b'7',
+        (Down, Right) | (Right, Down) => b'F',
+        _ => unreachable!("a pipe can't double back"),
+    }
+}
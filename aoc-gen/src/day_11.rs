@@ -0,0 +1,14 @@
+//! Day 11: Cosmic Expansion
+
+use rand::Rng;
+
+/// Generates a `size` by `size` image with galaxies scattered over about 2% of it, so that plenty
+/// of rows and columns are empty.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    crate::square_grid(
+        rng,
+        size,
+        |rng| if rng.gen_bool(0.02) { b'#' } else { b'.' },
+    )
+    .to_string()
+}
@@ -0,0 +1,36 @@
+//! Day 12: Hot Springs
+
+use rand::Rng;
+
+/// Generates `size` rows of up to 20 springs, each with at least one arrangement.
+///
+/// Each row is generated from a random arrangement of damaged groups, before hiding the condition
+/// of some of its springs.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let len = rng.gen_range(1..=20);
+            let mut conditions = String::new();
+            let mut groups = Vec::new();
+            while conditions.len() < len {
+                if rng.gen_bool(0.4) && !conditions.ends_with('#') {
+                    let group = rng.gen_range(1..=len - conditions.len());
+                    conditions.push_str(&"#".repeat(group));
+                    groups.push(group.to_string());
+                } else {
+                    conditions.push('.');
+                }
+            }
+            if groups.is_empty() {
+                conditions.replace_range(..1, "#");
+                groups.push("1".to_string());
+            }
+            let conditions = conditions
+                .chars()
+                .map(|c| if rng.gen_bool(0.5) { '?' } else { c })
+                .collect::<String>();
+            format!("{conditions} {}", groups.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,93 @@
+//! Day 13: Point of Incidence
+
+use aoc_grid::Grid;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates `size` patterns, each between 5 and 17 rows and columns.
+///
+/// Each pattern has exactly one line of reflection, and exactly one other line that would be a
+/// line of reflection if not for a single smudge.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| loop {
+            if let Some(pattern) = try_pattern(rng) {
+                break pattern.to_string();
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Tries to generate a pattern with a horizontal line of reflection and a horizontal smudged line,
+/// transposing it half of the time to make both lines vertical. Fails if any other line turns out
+/// to be a line of reflection or a smudged line, or if the smudge can't be placed.
+fn try_pattern(rng: &mut impl Rng) -> Option<Grid<u8>> {
+    let height = rng.gen_range(5..=17);
+    let width = rng.gen_range(5..=17);
+    let lines = (1..height).collect::<Vec<_>>();
+    let (reflection, smudged) = match lines.choose_multiple(rng, 2).collect::<Vec<_>>()[..] {
+        [&reflection, &smudged] => (reflection, smudged),
+        _ => unreachable!(),
+    };
+    // Rows reflected in either line must be equal, except for one pair across the smudged line,
+    // which must differ by a single cell. Group together the rows that must be equal.
+    let pairs = |line: usize| (0..line.min(height - line)).map(move |i| (line - 1 - i, line + i));
+    let smudge = *pairs(smudged).collect::<Vec<_>>().choose(rng).unwrap();
+    let mut groups = (0..height).collect::<Vec<_>>();
+    for (a, b) in pairs(reflection).chain(pairs(smudged).filter(|pair| *pair != smudge)) {
+        let (a, b) = (find(&mut groups, a), find(&mut groups, b));
+        groups[a] = b;
+    }
+    let (original, copy) = (find(&mut groups, smudge.0), find(&mut groups, smudge.1));
+    if original == copy {
+        return None;
+    }
+    let mut rows = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| if rng.gen() { b'#' } else { b'.' })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    rows[copy] = rows[original].clone();
+    let col = rng.gen_range(0..width);
+    rows[copy][col] = if rows[copy][col] == b'#' { b'.' } else { b'#' };
+    let cells = (0..height)
+        .flat_map(|row| rows[find(&mut groups, row)].clone())
+        .collect();
+    let pattern = Grid::new(width, cells);
+    let mut expected = vec![2; height - 1];
+    expected[reflection - 1] = 0;
+    expected[smudged - 1] = 1;
+    let horizontal = differences(&pattern).map(|count| count.min(2));
+    if !horizontal.eq(expected) || differences(&pattern.transpose()).any(|count| count < 2) {
+        return None;
+    }
+    Some(if rng.gen() {
+        pattern.transpose()
+    } else {
+        pattern
+    })
+}
+
+/// Finds the group a row belongs to.
+fn find(groups: &mut [usize], row: usize) -> usize {
+    if groups[row] != row {
+        groups[row] = find(groups, groups[row]);
+    }
+    groups[row]
+}
+
+/// Counts the cells that differ across each horizontal line, between rows 1 and 2, 2 and 3, and so
+/// on.
+fn differences(pattern: &Grid<u8>) -> impl Iterator<Item = usize> + '_ {
+    (1..pattern.height()).map(|line| {
+        (0..line.min(pattern.height() - line))
+            .map(|i| {
+                let (above, below) = (pattern.row(line - 1 - i), pattern.row(line + i));
+                above.iter().zip(below).filter(|(a, b)| a != b).count()
+            })
+            .sum()
+    })
+}
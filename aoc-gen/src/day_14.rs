@@ -0,0 +1,14 @@
+//! Day 14: Parabolic Reflector Dish
+
+use rand::Rng;
+
+/// Generates a `size` by `size` platform of round rocks (`O`), cube-shaped rocks (`#`) and empty
+/// space.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    crate::square_grid(rng, size, |rng| match rng.gen_range(0..20) {
+        0..=3 => b'O',
+        4..=5 => b'#',
+        _ => b'.',
+    })
+    .to_string()
+}
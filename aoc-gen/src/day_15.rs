@@ -0,0 +1,26 @@
+//! Day 15: Lens Library
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates `size` initialization steps, inserting and removing lenses with labels drawn from a
+/// pool of about a quarter as many labels, so that labels are reused.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let labels = (0..size / 4 + 1)
+        .map(|_| {
+            let len = rng.gen_range(2..=6);
+            crate::name(rng, len)
+        })
+        .collect::<Vec<_>>();
+    (0..size)
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.gen_bool(0.6) {
+                format!("{label}={}", rng.gen_range(1..=9))
+            } else {
+                format!("{label}-")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
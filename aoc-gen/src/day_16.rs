@@ -0,0 +1,17 @@
+//! Day 16: The Floor Will Be Lava
+
+use rand::Rng;
+
+const DEVICES: &[u8] = b"/\\|-";
+
+/// Generates a `size` by `size` contraption with mirrors and splitters in about a tenth of it.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    crate::square_grid(rng, size, |rng| {
+        if rng.gen_bool(0.1) {
+            DEVICES[rng.gen_range(0..DEVICES.len())]
+        } else {
+            b'.'
+        }
+    })
+    .to_string()
+}
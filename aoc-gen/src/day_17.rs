@@ -0,0 +1,9 @@
+//! Day 17: Clumsy Crucible
+
+use rand::Rng;
+
+/// Generates a `size` by `size` map of heat losses from 1 to 9. The map is at least 5 by 5, so that
+/// the ultra crucible can reach the factory.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    crate::square_grid(rng, size.max(5), |rng| rng.gen_range(b'1'..=b'9')).to_string()
+}
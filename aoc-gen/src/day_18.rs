@@ -0,0 +1,88 @@
+//! Day 18: Lavaduct Lagoon
+
+use crate::outline::outline;
+use aoc_grid::geometry::Direction::{self, Down, Left, Right, Up};
+use rand::Rng;
+
+/// Generates a dig plan that traces the outline of a random tree on a `size` by `size` grid, so
+/// that it never touches itself.
+///
+/// Both parts dig the same shape, but part 2's is rotated, and each of its corners is up to
+/// `0xfffff / (2 * size)` apart rather than up to 10.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let steps = outline(rng, size, 1);
+    // Space each row and column of the loop's lattice randomly, keeping the shape of the loop.
+    let mut spacing = |max: i64| -> (Vec<i64>, Vec<i64>) {
+        let mut positions = || {
+            (0..2 * size)
+                .scan(0, |position, _| {
+                    *position += rng.gen_range(1..=max);
+                    Some(*position)
+                })
+                .collect()
+        };
+        (positions(), positions())
+    };
+    let (rows_1, cols_1) = spacing(10);
+    let (rows_2, cols_2) = spacing((0xfffff / (2 * size as i64)).max(1));
+    let rotation = rng.gen_range(0..4);
+    // Merge the loop's unit steps into straight runs.
+    let mut runs: Vec<Run> = Vec::new();
+    let mut point = (0usize, 0usize);
+    for step in steps {
+        let (d_row, d_col) = step.delta();
+        let next = (
+            point.0.wrapping_add_signed(d_row),
+            point.1.wrapping_add_signed(d_col),
+        );
+        match runs.last_mut() {
+            Some(run) if run.direction == step => run.end = next,
+            _ => runs.push(Run {
+                direction: step,
+                start: point,
+                end: next,
+            }),
+        }
+        point = next;
+    }
+    runs.into_iter()
+        .map(
+            |Run {
+                 direction,
+                 start,
+                 end,
+             }| {
+                let distance = |rows: &[i64], cols: &[i64]| {
+                    (rows[start.0] - rows[end.0]).abs() + (cols[start.1] - cols[end.1]).abs()
+                };
+                let rotated = (0..rotation).fold(direction, |direction, _| direction.turn_right());
+                let digit = match rotated {
+                    Right => 0,
+                    Down => 1,
+                    Left => 2,
+                    Up => 3,
+                };
+                let letter = match direction {
+                    Right => 'R',
+                    Down => 'D',
+                    Left => 'L',
+                    Up => 'U',
+                };
+                format!(
+                    "{letter} {} (#{:05x}{digit})",
+                    distance(&rows_1, &cols_1),
+                    distance(&rows_2, &cols_2)
+                )
+            },
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A straight run of the loop, between two points of its lattice.
+struct Run {
+    direction: Direction,
+    start: (usize, usize),
+    end: (usize, usize),
+}
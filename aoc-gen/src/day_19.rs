@@ -0,0 +1,52 @@
+//! Day 19: Aplenty
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashSet, VecDeque};
+
+/// Generates a tree of `size` workflows, starting from `in`, and `size` parts to sort with them.
+///
+/// Each workflow has up to four rules, whose targets are either a new workflow, until there are
+/// `size` of them, or `A` or `R`. No workflow is the target of more than one rule, so no part can
+/// be sent round in a cycle.
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let mut names = HashSet::from(["in".to_string()]);
+    let mut created = 1;
+    let mut queue = VecDeque::from(["in".to_string()]);
+    let mut workflows = Vec::new();
+    while let Some(name) = queue.pop_front() {
+        let mut target = |rng: &mut R| {
+            if created < size && rng.gen_bool(0.7) {
+                created += 1;
+                loop {
+                    let len = rng.gen_range(2..=3);
+                    let name = crate::name(rng, len);
+                    if names.insert(name.clone()) {
+                        queue.push_back(name.clone());
+                        return name;
+                    }
+                }
+            } else {
+                ["A", "R"].choose(rng).unwrap().to_string()
+            }
+        };
+        let rules = (0..rng.gen_range(1..=4))
+            .map(|_| {
+                let category = ['x', 'm', 'a', 's'].choose(rng).unwrap();
+                let comparison = ['<', '>'].choose(rng).unwrap();
+                let value = rng.gen_range(1..=4000);
+                format!("{category}{comparison}{value}:{}", target(rng))
+            })
+            .collect::<Vec<_>>();
+        workflows.push(format!("{name}{{{},{}}}", rules.join(","), target(rng)));
+    }
+    workflows.shuffle(rng);
+    let parts = (0..size)
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+            format!("{{x={x},m={m},a={a},s={s}}}")
+        })
+        .collect::<Vec<_>>();
+    format!("{}\n\n{}", workflows.join("\n"), parts.join("\n"))
+}
@@ -0,0 +1,25 @@
+//! Day 2: Cube Conundrum
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates `size` games, each of one to six rounds revealing up to 20 cubes of each colour.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            let rounds = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let mut colours = ["red", "green", "blue"];
+                    colours.shuffle(rng);
+                    colours[..rng.gen_range(1..=3)]
+                        .iter()
+                        .map(|colour| format!("{} {colour}", rng.gen_range(1..=20)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect::<Vec<_>>();
+            format!("Game {id}: {}", rounds.join("; "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
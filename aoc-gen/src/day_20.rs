@@ -0,0 +1,81 @@
+//! Day 20: Pulse Propagation
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+/// Generates a network of four binary counters, each of `size / 4` flip-flops (between 2 and 16),
+/// like the official inputs.
+///
+/// Each counter is a chain of flip-flops counting button presses, and a conjunction that resets
+/// the count and sends a high pulse towards `rx` whenever it reaches the counter's period. The
+/// counter's flip-flops for the period's set bits send pulses to the conjunction, and the
+/// conjunction sends pulses to those for its unset bits, and to the first. Part 2's answer is the
+/// product of the four periods.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    network(rng, (size / 4).clamp(2, 16)).0
+}
+
+/// Generates a network of counters of `bits` flip-flops, returning it along with their periods.
+fn network(rng: &mut impl Rng, bits: usize) -> (String, [u64; 4]) {
+    let mut names = HashSet::from(["rx".to_string()]);
+    let mut name = |rng: &mut _| loop {
+        let name = crate::name(rng, 2);
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+    let output = name(rng);
+    let mut lines = vec![format!("&{output} -> rx")];
+    let mut firsts = Vec::new();
+    let periods = [(); 4].map(|_| {
+        // The highest bit is set, so that the counter needs every flip-flop, and so is the lowest.
+        let period = rng.gen_range(1 << (bits - 1)..1 << bits) | 1;
+        let flip_flops = (0..bits).map(|_| name(rng)).collect::<Vec<_>>();
+        let (hub, inverter) = (name(rng), name(rng));
+        let mut hub_destinations = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut destinations = flip_flops.get(bit + 1).into_iter().collect::<Vec<_>>();
+            if period & (1 << bit) == 0 {
+                hub_destinations.push(flip_flop.clone());
+            } else {
+                destinations.push(&hub);
+            }
+            destinations.shuffle(rng);
+            let destinations = destinations
+                .iter()
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>();
+            lines.push(format!("%{flip_flop} -> {}", destinations.join(", ")));
+        }
+        hub_destinations.push(inverter.clone());
+        hub_destinations.shuffle(rng);
+        lines.push(format!("&{hub} -> {}", hub_destinations.join(", ")));
+        lines.push(format!("&{inverter} -> {output}"));
+        firsts.push(flip_flops[0].clone());
+        period
+    });
+    lines.push(format!("broadcaster -> {}", firsts.join(", ")));
+    lines.shuffle(rng);
+    (lines.join("\n"), periods)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn counters() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        for bits in 2..=8 {
+            let (input, periods) = network(rng, bits);
+            let configuration = day_20::parse(&input).unwrap();
+            assert_eq!(
+                day_20::part_2(&configuration),
+                Some(periods.iter().product::<u64>() as usize)
+            );
+        }
+    }
+}
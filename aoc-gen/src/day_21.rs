@@ -0,0 +1,16 @@
+//! Day 21: Step Counter
+
+use rand::Rng;
+
+/// Generates a `size` by `size` map of garden plots, with rocks in about a sixth of it, and the
+/// starting position, `S`, in the middle.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut garden = crate::square_grid(
+        rng,
+        size,
+        |rng| if rng.gen_bool(0.15) { b'#' } else { b'.' },
+    );
+    garden[(size / 2, size / 2)] = b'S';
+    garden.to_string()
+}
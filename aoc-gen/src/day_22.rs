@@ -0,0 +1,40 @@
+//! Day 22: Sand Slabs
+
+use rand::Rng;
+use std::collections::HashSet;
+
+/// Generates `size` bricks, each up to 5 cubes long, in a 10 by 10 column as tall as it needs to
+/// be for the bricks to fill about a fifth of it. No two bricks overlap.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let height = (size * 3 / 20).max(1);
+    let mut occupied = HashSet::new();
+    let mut bricks = Vec::new();
+    while bricks.len() < size {
+        let start = [
+            rng.gen_range(0..10),
+            rng.gen_range(0..10),
+            rng.gen_range(1..=height),
+        ];
+        let axis = rng.gen_range(0..3);
+        let mut end = start;
+        end[axis] += rng.gen_range(0..5);
+        if axis < 2 && end[axis] >= 10 {
+            continue;
+        }
+        let cubes = (start[axis]..=end[axis])
+            .map(|i| {
+                let mut cube = start;
+                cube[axis] = i;
+                cube
+            })
+            .collect::<Vec<_>>();
+        if cubes.iter().any(|cube| occupied.contains(cube)) {
+            continue;
+        }
+        occupied.extend(cubes);
+        let [x1, y1, z1] = start;
+        let [x2, y2, z2] = end;
+        bricks.push(format!("{x1},{y1},{z1}~{x2},{y2},{z2}"));
+    }
+    bricks.join("\n")
+}
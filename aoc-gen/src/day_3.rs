@@ -0,0 +1,29 @@
+//! Day 3: Gear Ratios
+
+use rand::Rng;
+
+const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+/// Generates a `size` by `size` engine schematic of numbers, symbols and `.`s.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut schematic = crate::square_grid(rng, size, |_| b'.');
+    for row in 0..size {
+        let mut col = rng.gen_range(0..4);
+        while col < size {
+            if rng.gen_bool(0.2) {
+                schematic[(row, col)] = SYMBOLS[rng.gen_range(0..SYMBOLS.len())];
+                col += 1;
+            } else {
+                // Numbers never start with a zero, and are always followed by a `.` or a symbol.
+                let digits = rng.gen_range(1..=3).min(size - col);
+                schematic[(row, col)] = rng.gen_range(b'1'..=b'9');
+                for col in col + 1..col + digits {
+                    schematic[(row, col)] = rng.gen_range(b'0'..=b'9');
+                }
+                col += digits;
+            }
+            col += rng.gen_range(1..6);
+        }
+    }
+    schematic.to_string()
+}
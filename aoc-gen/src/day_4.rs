@@ -0,0 +1,39 @@
+//! Day 4: Scratchcards
+
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+/// Generates `size` scratchcards, each with 10 winning numbers and 25 numbers you have.
+///
+/// Most cards win nothing, and few win more than a couple of copies, so that the number of copies
+/// won in part 2 doesn't grow exponentially with the number of cards. No card wins copies of cards
+/// past the end of the table.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            let numbers = index::sample(rng, 99, 35)
+                .into_iter()
+                .map(|number| number + 1)
+                .collect::<Vec<_>>();
+            let (winning, others) = numbers.split_at(10);
+            let wins = match rng.gen_range(0..20) {
+                0..=11 => 0,
+                12..=16 => 1,
+                17..=18 => 2,
+                _ => rng.gen_range(3..=10),
+            }
+            .min(size - id);
+            let mut have = [&winning[..wins], &others[..25 - wins]].concat();
+            have.shuffle(rng);
+            let join = |numbers: &[usize]| {
+                numbers
+                    .iter()
+                    .map(|number| format!("{number:2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            format!("Card {id:3}: {} | {}", join(winning), join(&have))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
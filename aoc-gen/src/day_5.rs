@@ -0,0 +1,51 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Generates an almanac of `size` seed ranges, and `size` ranges in each map.
+///
+/// Seed ranges are at most 1000 long, so that part 2's brute force over every seed stays quick.
+/// Each map's source ranges never overlap.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let limit = 1000 * size as u64;
+    let seeds = (0..size)
+        .map(|_| {
+            let start = rng.gen_range(0..limit);
+            format!("{start} {}", rng.gen_range(1..=1000))
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let maps = MAPS.map(|name| {
+        // Split the numbers up to the limit into `size` source ranges, moving each one to a random
+        // destination.
+        let mut bounds = (0..size - 1)
+            .map(|_| rng.gen_range(0..limit))
+            .collect::<Vec<_>>();
+        bounds.extend([0, limit]);
+        bounds.sort();
+        let mut ranges = bounds
+            .windows(2)
+            .filter(|bounds| bounds[1] > bounds[0])
+            .map(|bounds| {
+                let length = bounds[1] - bounds[0];
+                let destination = rng.gen_range(0..limit);
+                format!("{destination} {} {length}", bounds[0])
+            })
+            .collect::<Vec<_>>();
+        ranges.shuffle(rng);
+        format!("{name} map:\n{}", ranges.join("\n"))
+    });
+    format!("seeds: {seeds}\n\n{}", maps.join("\n\n"))
+}
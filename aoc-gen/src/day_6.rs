@@ -0,0 +1,36 @@
+//! Day 6: Wait For It
+
+use rand::Rng;
+
+/// Generates `size` races, up to a maximum of four, since part 2 reads every race as a single race
+/// whose record distance has to fit in a `u64`. Every race, including part 2's, can be won.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let races = size.clamp(1, 4);
+    loop {
+        let times = (0..races)
+            .map(|_| rng.gen_range(10..100u64))
+            .collect::<Vec<_>>();
+        let distances = times
+            .iter()
+            .map(|time| rng.gen_range(1..time * time / 4))
+            .collect::<Vec<_>>();
+        let concat = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(u64::to_string)
+                .collect::<String>()
+                .parse::<u64>()
+                .unwrap()
+        };
+        if 4 * concat(&distances) >= concat(&times).pow(2) {
+            continue;
+        }
+        let line = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|number| format!("{number:>6}"))
+                .collect::<String>()
+        };
+        return format!("Time:   {}\nDistance:{}", line(&times), line(&distances));
+    }
+}
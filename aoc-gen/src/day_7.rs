@@ -0,0 +1,22 @@
+//! Day 7: Camel Cards
+
+use rand::Rng;
+use std::collections::HashSet;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Generates `size` distinct hands, each with a bid of up to 1000, up to the 13^5 possible hands.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.min(CARDS.len().pow(5));
+    let mut hands = HashSet::new();
+    let mut lines = Vec::new();
+    while lines.len() < size {
+        let hand = (0..5)
+            .map(|_| CARDS[rng.gen_range(0..CARDS.len())] as char)
+            .collect::<String>();
+        if hands.insert(hand.clone()) {
+            lines.push(format!("{hand} {}", rng.gen_range(1..=1000)));
+        }
+    }
+    lines.join("\n")
+}
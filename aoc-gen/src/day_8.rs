@@ -0,0 +1,63 @@
+//! Day 8: Haunted Wasteland
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+/// Generates a map of roughly `size` nodes, with up to six starting nodes ending in `A`, one of
+/// which is `AAA`.
+///
+/// Each start leads into its own cycle of layers of one or two nodes, in which every node's
+/// neighbours are both in the next layer. Every path through the layers therefore reaches the
+/// cycle's node ending in `Z` (`ZZZ` for `AAA`) after the same number of steps, whatever the
+/// instructions, and then starts the cycle again. Part 2's answer is the lowest common multiple of
+/// the cycles' lengths.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.clamp(2, 10_000);
+    let ghosts = (size / 20).clamp(1, 6);
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut _, last: Option<char>| loop {
+        let name = crate::name(rng, 3).to_uppercase();
+        let name = match last {
+            Some(last) => format!("{}{last}", &name[..2]),
+            None if name.ends_with(['A', 'Z']) => continue,
+            None => name,
+        };
+        if name != "AAA" && name != "ZZZ" && names.insert(name.clone()) {
+            return name;
+        }
+    };
+    let mut nodes = Vec::new();
+    for ghost in 0..ghosts {
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (name(rng, Some('A')), name(rng, Some('Z')))
+        };
+        let length = rng.gen_range((size / ghosts / 2).max(2)..=(size / ghosts).max(2));
+        let mut layers = vec![vec![start.clone()]];
+        for _ in 1..length {
+            let width = rng.gen_range(1..=2);
+            layers.push((0..width).map(|_| name(rng, None)).collect());
+        }
+        layers.push(vec![end.clone()]);
+        // The end node leads back into the first layer, just like the start.
+        let first_layer = layers[1].clone();
+        for (layer, next) in layers
+            .iter()
+            .zip(&layers[1..])
+            .chain([(&vec![end], &first_layer)])
+        {
+            for node in layer {
+                let left = next.choose(rng).unwrap();
+                let right = next.choose(rng).unwrap();
+                nodes.push(format!("{node} = ({left}, {right})"));
+            }
+        }
+    }
+    nodes.shuffle(rng);
+    let steps = (0..rng.gen_range(1..=size))
+        .map(|_| if rng.gen() { 'L' } else { 'R' })
+        .collect::<String>();
+    format!("{steps}\n\n{}", nodes.join("\n"))
+}
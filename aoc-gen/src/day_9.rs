@@ -0,0 +1,26 @@
+//! Day 9: Mirage Maintenance
+
+use rand::Rng;
+
+/// Generates `size` histories of 21 values, each read from a random polynomial of degree up to 5,
+/// so that their differences always reach zero.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let coefficients = (0..=rng.gen_range(0..=5))
+                .map(|_| rng.gen_range(-20..=20))
+                .collect::<Vec<i64>>();
+            (0..21)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |value, coefficient| value * x + coefficient)
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
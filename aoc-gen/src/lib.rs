@@ -0,0 +1,134 @@
+//! Seeded generators of valid puzzle inputs, of any size, for stress testing and benchmarking the
+//! solutions well beyond the size of the official inputs.
+//!
+//! Every day has a generator, taking a random number generator and a `size`. What the size
+//! measures depends on the day, such as the number of lines or the side of a grid, and is
+//! documented on each generator. The same seed and size always generate the same input.
+
+use aoc_grid::Grid;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_2;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+mod outline;
+
+/// Generates an input for the given day from `seed`, or `None` if the day has no generator.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+    Some(match day {
+        1 => day_1::generate(rng, size),
+        2 => day_2::generate(rng, size),
+        3 => day_3::generate(rng, size),
+        4 => day_4::generate(rng, size),
+        5 => day_5::generate(rng, size),
+        6 => day_6::generate(rng, size),
+        7 => day_7::generate(rng, size),
+        8 => day_8::generate(rng, size),
+        9 => day_9::generate(rng, size),
+        10 => day_10::generate(rng, size),
+        11 => day_11::generate(rng, size),
+        12 => day_12::generate(rng, size),
+        13 => day_13::generate(rng, size),
+        14 => day_14::generate(rng, size),
+        15 => day_15::generate(rng, size),
+        16 => day_16::generate(rng, size),
+        17 => day_17::generate(rng, size),
+        18 => day_18::generate(rng, size),
+        19 => day_19::generate(rng, size),
+        20 => day_20::generate(rng, size),
+        21 => day_21::generate(rng, size),
+        22 => day_22::generate(rng, size),
+        _ => return None,
+    })
+}
+
+/// Generates a `side` by `side` grid, choosing each cell with `f`.
+fn square_grid<R: Rng>(rng: &mut R, side: usize, mut f: impl FnMut(&mut R) -> u8) -> Grid<u8> {
+    Grid::new(side, (0..side * side).map(|_| f(rng)).collect())
+}
+
+/// Generates a lowercase name of `len` letters.
+fn name(rng: &mut impl Rng, len: usize) -> String {
+    (0..len).map(|_| rng.gen_range('a'..='z')).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Generates a few small inputs for a day, and checks that they parse and can be solved.
+    macro_rules! check_day {
+        ($day:literal, $krate:ident, $size:expr, [$($part:ident),+] $(, $bytes:ident)?) => {
+            for seed in 0..4 {
+                let input = generate($day, $size, seed).unwrap();
+                let puzzle = match ::$krate::parse(input.as_str()$(.$bytes())?) {
+                    Ok(puzzle) => puzzle,
+                    Err(err) => panic!("seed {seed}: {}", err.render(&input, "generated")),
+                };
+                $(let _ = ::$krate::$part(&puzzle);)+
+            }
+        };
+    }
+
+    #[test]
+    fn deterministic() {
+        for day in 1..=22 {
+            assert_eq!(generate(day, 10, 1), generate(day, 10, 1));
+            assert_ne!(generate(day, 10, 1), generate(day, 10, 2));
+        }
+        assert_eq!(generate(23, 10, 1), None);
+    }
+
+    #[test]
+    fn solvable() {
+        check_day!(1, day_1, 50, [part_1, part_2], as_bytes);
+        check_day!(2, day_2, 50, [part_1, part_2]);
+        check_day!(3, day_3, 50, [part_1, part_2], as_bytes);
+        check_day!(4, day_4, 50, [part_1, part_2]);
+        check_day!(5, day_5, 20, [part_1, part_2]);
+        check_day!(6, day_6, 4, [part_1, part_2]);
+        check_day!(7, day_7, 50, [part_1, part_2]);
+        check_day!(8, day_8, 50, [part_1, part_2]);
+        check_day!(9, day_9, 50, [part_1, part_2]);
+        check_day!(10, day_10, 30, [part_1, part_2], as_bytes);
+        check_day!(11, day_11, 50, [part_1, part_2], as_bytes);
+        check_day!(12, day_12, 50, [part_1, part_2]);
+        check_day!(13, day_13, 20, [part_1, part_2]);
+        check_day!(14, day_14, 30, [part_1, part_2]);
+        check_day!(15, day_15, 50, [part_1, part_2], as_bytes);
+        check_day!(16, day_16, 20, [part_1, part_2], as_bytes);
+        check_day!(17, day_17, 10, [part_1, part_2], as_bytes);
+        check_day!(18, day_18, 20, [part_1, part_2]);
+        check_day!(19, day_19, 50, [part_1, part_2]);
+        check_day!(20, day_20, 24, [part_1, part_2]);
+        check_day!(21, day_21, 30, [part_1]);
+        check_day!(22, day_22, 30, [part_1, part_2]);
+
+        // Every line has a numeric digit, so part 1 can't reject any.
+        for seed in 0..4 {
+            let input = generate(1, 50, seed).unwrap();
+            assert!(::day_1::run_1(input.as_bytes()).is_ok(), "seed {seed}");
+        }
+    }
+}
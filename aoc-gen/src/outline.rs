@@ -0,0 +1,131 @@
+use aoc_grid::geometry::Direction::{self, Down, Left, Right, Up};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates a random simple loop on a square lattice of `side * (thickness + 1)` points along each
+/// side, as the unit steps taken to walk it clockwise from the top-left point, `(0, 0)`.
+///
+/// The loop is the outline of a random tree, spanning about half of a `side` by `side` grid of
+/// nodes, drawn with thick lines: each node fills a `thickness` by `thickness` block of cells, and
+/// each edge fills the gap of one cell between its nodes' blocks. A tree has no cycles, so the
+/// filled cells have no holes, and every corner of a filled cell touches a node's block, so they
+/// never meet only diagonally. Their outline is therefore a single loop that never touches itself.
+pub fn outline(rng: &mut impl Rng, side: usize, thickness: usize) -> Vec<Direction> {
+    let side = side.max(1);
+    let stride = thickness.max(1) + 1;
+    let mut visited = vec![false; side * side];
+    // The edges from each node to the node below it, and to the node to its right.
+    let mut down = vec![false; side * side];
+    let mut right = vec![false; side * side];
+    // A randomised depth-first search, which wanders further than other spanning trees, stopping
+    // once it has visited half of the nodes.
+    let target = (side * side).div_ceil(2);
+    let mut count = 1;
+    let mut stack = vec![(0usize, 0usize)];
+    visited[0] = true;
+    while let Some(&(row, col)) = stack.last() {
+        if count == target {
+            break;
+        }
+        let mut directions = Direction::ALL;
+        directions.shuffle(rng);
+        let next = directions.into_iter().find_map(|direction| {
+            let (d_row, d_col) = direction.delta();
+            let next = (
+                row.checked_add_signed(d_row)?,
+                col.checked_add_signed(d_col)?,
+            );
+            (next.0 < side && next.1 < side && !visited[next.0 * side + next.1]).then_some(next)
+        });
+        match next {
+            Some((next_row, next_col)) => {
+                visited[next_row * side + next_col] = true;
+                if next_row == row {
+                    right[row * side + col.min(next_col)] = true;
+                } else {
+                    down[row.min(next_row) * side + col] = true;
+                }
+                stack.push((next_row, next_col));
+                count += 1;
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    // Cells are offset by one, so that point `(row, col)` is the top-left corner of cell
+    // `(row, col)`, and the cells above and left of the lattice are empty.
+    let cells = side * stride - 1;
+    let is_filled = |row: usize, col: usize| -> bool {
+        if row == 0 || col == 0 || row > cells || col > cells {
+            return false;
+        }
+        let (row, col) = (row - 1, col - 1);
+        let node = row / stride * side + col / stride;
+        match (row % stride == stride - 1, col % stride == stride - 1) {
+            (false, false) => visited[node],
+            (true, false) => down[node],
+            (false, true) => right[node],
+            (true, true) => false,
+        }
+    };
+    // A step from a point is along the outline if the cells either side of it differ.
+    let on_outline = |(row, col): (usize, usize), direction: Direction| match direction {
+        Up => row > 0 && is_filled(row, col) != is_filled(row, col + 1),
+        Down => is_filled(row + 1, col) != is_filled(row + 1, col + 1),
+        Left => col > 0 && is_filled(row, col) != is_filled(row + 1, col),
+        Right => is_filled(row, col + 1) != is_filled(row + 1, col + 1),
+    };
+    let mut steps = Vec::new();
+    let mut point = (0usize, 0usize);
+    let mut facing = Right;
+    loop {
+        steps.push(facing);
+        let (d_row, d_col) = facing.delta();
+        point = (
+            point.0.wrapping_add_signed(d_row),
+            point.1.wrapping_add_signed(d_col),
+        );
+        if point == (0, 0) {
+            return steps;
+        }
+        facing = Direction::ALL
+            .into_iter()
+            .find(|&direction| direction != facing.reverse() && on_outline(point, direction))
+            .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn simple_loop() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        assert_eq!(outline(rng, 1, 1), [Right, Down, Left, Up]);
+        assert_eq!(
+            outline(rng, 1, 2),
+            [Right, Right, Down, Down, Left, Left, Up, Up]
+        );
+        for side in 2..10 {
+            for thickness in 1..4 {
+                let steps = outline(rng, side, thickness);
+                let points = (side * (thickness + 1)) as isize;
+                let mut point = (0, 0);
+                let mut visited = HashSet::new();
+                for step in &steps {
+                    let (d_row, d_col) = step.delta();
+                    point = (point.0 + d_row, point.1 + d_col);
+                    assert!((0..points).contains(&point.0));
+                    assert!((0..points).contains(&point.1));
+                    assert!(visited.insert(point), "{point:?} visited twice");
+                }
+                assert_eq!(point, (0, 0));
+            }
+        }
+    }
+}
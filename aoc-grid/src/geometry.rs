@@ -0,0 +1,174 @@
+//! Directions, and points and vectors on an unbounded plane.
+//!
+//! As in a [`Grid`](crate::Grid), up is towards the first row, so moving [`Direction::Down`]
+//! increases `y`.
+
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from [`Direction::Up`].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The direction facing the opposite way.
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The direction a quarter turn clockwise from this one.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// The direction a quarter turn counterclockwise from this one.
+    pub fn turn_left(self) -> Self {
+        self.reverse().turn_right()
+    }
+
+    /// Whether this is [`Direction::Up`] or [`Direction::Down`].
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// The `(row, column)` offset of a single step in this direction, as taken by
+    /// [`Grid::offset`](crate::Grid::offset).
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+
+    /// A single step in this direction.
+    pub fn vector(self) -> Vector {
+        let (y, x) = self.delta();
+        Vector {
+            x: x as i64,
+            y: y as i64,
+        }
+    }
+}
+
+/// A point on the plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves this point by `vector`, returning `None` if that would overflow.
+    pub fn checked_add(self, vector: Vector) -> Option<Point> {
+        Some(Point::new(
+            self.x.checked_add(vector.x)?,
+            self.y.checked_add(vector.y)?,
+        ))
+    }
+
+    /// The number of orthogonal steps between this point and `other`.
+    pub fn manhattan_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// The displacement between two [`Point`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Vector {
+        Vector {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: i64) -> Vector {
+        Vector {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_ne!(direction.reverse(), direction);
+            assert_eq!(
+                direction.turn_right().is_vertical(),
+                !direction.is_vertical()
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+    }
+
+    #[test]
+    fn vectors() {
+        let start = Point::new(2, -1);
+        let mut point = start;
+        point += Direction::Down.vector() * 3;
+        point += Direction::Left.vector() * 4;
+        assert_eq!(point, Point::new(-2, 2));
+        assert_eq!(point - start, Vector { x: -4, y: 3 });
+        assert_eq!(point.manhattan_distance(start), 7);
+        assert_eq!(Point::ORIGIN + Direction::Up.vector(), Point::new(0, -1));
+    }
+}
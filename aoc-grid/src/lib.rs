@@ -0,0 +1,435 @@
+//! A dense, row-major 2D grid, shared by the days whose puzzle input is a map of characters.
+//!
+//! Positions are `(row, column)` pairs, with `(0, 0)` in the top-left corner. The [`geometry`]
+//! module has the [`Direction`]s to move between them.
+
+use aoc_common::parse::ParseError;
+use geometry::Direction;
+use std::fmt::{self, Display, Formatter, Write};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+pub mod geometry;
+
+/// The offsets to each orthogonal and diagonal neighbour, in reading order.
+const ALL: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells isn't a multiple of `width`.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "cells don't fill every row");
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a `width` by `height` grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, vec![value; width * height])
+    }
+
+    /// Parses a grid from lines of text, converting each byte into a cell with `f`. A trailing new
+    /// line, and the carriage return of any CRLF line ending, are ignored. The grid must have at
+    /// least one cell.
+    pub fn parse_with<F>(input: &[u8], mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(u8) -> T,
+    {
+        Self::try_parse_with(input, |b| Ok::<_, String>(f(b)))
+    }
+
+    /// Like [`Grid::parse_with`], but `f` may reject a byte with a message describing what was
+    /// expected instead.
+    pub fn try_parse_with<F, E>(input: &[u8], mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(u8) -> Result<T, E>,
+        E: Into<String>,
+    {
+        let source = input;
+        let input = input.strip_suffix(b"\n").unwrap_or(input);
+        if input.is_empty() {
+            return Err(ParseError::end_of_input(source, "expected a grid"));
+        }
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        for row in input.split(|b| *b == b'\n') {
+            let row = row.strip_suffix(b"\r").unwrap_or(row);
+            if row.is_empty() && width.is_none() {
+                return Err(ParseError::new(source, row, "expected a cell"));
+            }
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                let span = &row[expected.min(row.len())..];
+                let message = format!("expected a row of {expected} cells");
+                return Err(ParseError::new(source, span, message));
+            }
+            for (col_idx, b) in row.iter().enumerate() {
+                let cell = f(*b).map_err(|message| {
+                    ParseError::new(source, &row[col_idx..col_idx + 1], message)
+                })?;
+                cells.push(cell);
+            }
+        }
+        Ok(Self::new(width.expect("the grid has a row"), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(row, col)` is within the grid.
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[self.idx(pos)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.contains(pos) {
+            let idx = self.idx(pos);
+            Some(&mut self.cells[idx])
+        } else {
+            None
+        }
+    }
+
+    /// Moves from `pos` by `(d_row, d_col)`, returning `None` if that would leave the grid.
+    pub fn offset(
+        &self,
+        (row, col): (usize, usize),
+        (d_row, d_col): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        if self.contains(pos) {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    /// Moves one step from `pos` in `direction`, returning `None` if that would leave the grid.
+    pub fn step(&self, pos: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        self.offset(pos, direction.delta())
+    }
+
+    /// The positions orthogonally adjacent to `pos` that are within the grid, clockwise from the one
+    /// above it.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The positions orthogonally or diagonally adjacent to `pos` that are within the grid.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL.iter().filter_map(move |delta| self.offset(pos, *delta))
+    }
+
+    /// The cells of row `row`.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self[row]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// The cells of column `col`, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If `col` is out of bounds.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(col < self.width, "column {col} is out of bounds");
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every position in the grid, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell in the grid, along with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Finds the position of the first cell, in row-major order, that satisfies `predicate`.
+    pub fn position<P>(&self, predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|idx| (idx / self.width, idx % self.width))
+    }
+
+    /// Converts each cell with `f`, keeping the grid's shape.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.cells.iter().map(f).collect())
+    }
+
+    /// Reflects the grid along its leading diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |(row, col)| (col, row))
+    }
+
+    /// Rotates the grid 90° clockwise, so that its left column becomes its top row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |(row, col)| {
+            (self.height - 1 - col, row)
+        })
+    }
+
+    /// Rotates the grid 90° counterclockwise, so that its right column becomes its top row.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |(row, col)| {
+            (col, self.width - 1 - row)
+        })
+    }
+
+    /// The grid's cells in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Builds a `width` by `height` grid where each cell is copied from the position in this grid
+    /// given by `source`.
+    fn rearrange<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        T: Clone,
+        F: Fn((usize, usize)) -> (usize, usize),
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Grid::new(width, cells)
+    }
+
+    fn idx(&self, (row, col): (usize, usize)) -> usize {
+        row * self.width + col
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        assert!(self.contains(pos), "{pos:?} is out of bounds");
+        &self.cells[self.idx(pos)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        assert!(self.contains(pos), "{pos:?} is out of bounds");
+        let idx = self.idx(pos);
+        &mut self.cells[idx]
+    }
+}
+
+/// Indexes a whole row, so that cells can also be accessed with `grid[row][col]`.
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s.as_bytes(), |b| b)
+    }
+}
+
+/// Writes each row of the grid on its own line, without a trailing new line, so that a `Grid<u8>`
+/// is written exactly as it was parsed.
+impl<T> Display for Grid<T>
+where
+    T: Copy + Into<char>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (row_idx, row) in self.rows().enumerate() {
+            if row_idx != 0 {
+                f.write_char('\n')?;
+            }
+            for cell in row {
+                f.write_char((*cell).into())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc
+def";
+
+    #[test]
+    fn parse() {
+        let grid = INPUT.parse::<Grid<u8>>().unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(1, 0)], b'd');
+        assert_eq!(grid[1][2], b'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.position(|b| *b == b'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn parse_line_endings() {
+        let grid = "abc\r\ndef\r\n".parse::<Grid<u8>>().unwrap();
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn parse_empty() {
+        for input in ["", "\n", "\r\n", "\n\n"] {
+            assert!(input.parse::<Grid<u8>>().is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn parse_ragged() {
+        assert_eq!(
+            "abc\nde\nf".parse::<Grid<u8>>(),
+            Err(ParseError {
+                line: 2,
+                column: 3,
+                text: String::new(),
+                message: "expected a row of 3 cells".to_string(),
+            })
+        );
+        let err = "ab\ncde".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "e"));
+    }
+
+    #[test]
+    fn parse_invalid_cell() {
+        let err = Grid::try_parse_with(b"..\n.x", |b| match b {
+            b'.' => Ok(()),
+            _ => Err("expected `.`"),
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let grid = INPUT.parse::<Grid<u8>>().unwrap();
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = INPUT.parse::<Grid<u8>>().unwrap();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours8((0, 1)).collect::<Vec<_>>(),
+            [(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+        assert_eq!(grid.offset((1, 2), (0, 1)), None);
+        assert_eq!(grid.step((1, 2), Direction::Right), None);
+        assert_eq!(grid.step((1, 2), Direction::Up), Some((0, 2)));
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+        assert_eq!(grid.offset((1, 2), (-1, -2)), Some((0, 0)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = INPUT.parse::<Grid<u8>>().unwrap();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [b"abc", b"def"]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn transpose() {
+        let grid = INPUT.parse::<Grid<u8>>().unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn rotate() {
+        let grid = INPUT.parse::<Grid<u8>>().unwrap();
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
@@ -0,0 +1,238 @@
+//! Renders puzzles as pictures for debugging: grids as raster images in the binary PPM format,
+//! shapes as SVG, and the steps of a simulation as frames of coloured text for a terminal. Every
+//! format is simple enough to write by hand, so rendering needs nothing but the standard library.
+
+use aoc_grid::geometry::Point;
+use aoc_grid::Grid;
+use std::fmt::{self, Display, Formatter, Write as _};
+use std::io::{self, Write};
+
+/// A colour, as its red, green and blue components.
+pub type Rgb = [u8; 3];
+
+/// A raster image, with a pixel for each cell of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<Rgb>,
+}
+
+impl Image {
+    /// Creates an image of the grid, colouring each cell with `f`.
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl Fn(&T) -> Rgb) -> Self {
+        Self {
+            pixels: grid.map(f),
+        }
+    }
+
+    /// Recolours the given `(row, column)` cells. Cells outside the image are ignored.
+    pub fn paint(&mut self, cells: impl IntoIterator<Item = (usize, usize)>, colour: Rgb) {
+        for cell in cells {
+            if let Some(pixel) = self.pixels.get_mut(cell) {
+                *pixel = colour;
+            }
+        }
+    }
+
+    /// Writes the image as a binary PPM, drawing each cell as a `scale` by `scale` square of pixels.
+    pub fn write_ppm(&self, mut writer: impl Write, scale: usize) -> io::Result<()> {
+        let scale = scale.max(1);
+        let (width, height) = (self.pixels.width(), self.pixels.height());
+        write!(writer, "P6\n{} {}\n255\n", width * scale, height * scale)?;
+        for row in self.pixels.rows() {
+            let line = row
+                .iter()
+                .flat_map(|pixel| pixel.repeat(scale))
+                .collect::<Vec<_>>();
+            for _ in 0..scale {
+                writer.write_all(&line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A vector image of polygons, which scales to fit them all.
+#[derive(Debug, Clone, Default)]
+pub struct Svg {
+    /// The top-left and bottom-right corners of the shapes drawn so far.
+    bounds: Option<(Point, Point)>,
+    shapes: Vec<String>,
+}
+
+impl Svg {
+    /// Draws a closed polygon through the points, in order.
+    pub fn polygon(&mut self, points: &[Point], fill: Rgb, stroke: Rgb) {
+        for &point in points {
+            self.bounds = Some(match self.bounds {
+                None => (point, point),
+                Some((min, max)) => (
+                    Point::new(min.x.min(point.x), min.y.min(point.y)),
+                    Point::new(max.x.max(point.x), max.y.max(point.y)),
+                ),
+            });
+        }
+        let mut path = String::new();
+        for point in points {
+            write!(path, "{},{} ", point.x, point.y).unwrap();
+        }
+        self.shapes.push(format!(
+            r#"<polygon points="{}" fill="{}" stroke="{}" stroke-width="1" vector-effect="non-scaling-stroke"/>"#,
+            path.trim_end(),
+            hex(fill),
+            hex(stroke),
+        ));
+    }
+
+    /// Writes the SVG document, `size` pixels along its longest side.
+    pub fn write(&self, mut writer: impl Write, size: usize) -> io::Result<()> {
+        let (min, max) = self.bounds.unwrap_or_default();
+        // Leave a margin, so that strokes along the edges aren't cut in half.
+        let margin = ((max.x - min.x).max(max.y - min.y) / 50).max(1);
+        let (x, y) = (min.x - margin, min.y - margin);
+        let (width, height) = (max.x - x + margin, max.y - y + margin);
+        let scale = size as f64 / width.max(height) as f64;
+        writeln!(
+            writer,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{x} {y} {width} {height}" width="{:.0}" height="{:.0}">"#,
+            width as f64 * scale,
+            height as f64 * scale,
+        )?;
+        for shape in &self.shapes {
+            writeln!(writer, "  {shape}")?;
+        }
+        writeln!(writer, "</svg>")
+    }
+}
+
+/// A frame of a terminal animation: a grid of characters, each in its own colour, or the terminal's
+/// default colour if `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<(char, Option<Rgb>)>,
+}
+
+impl Frame {
+    /// Creates a frame of spaces.
+    pub fn blank(width: usize, height: usize) -> Self {
+        Self {
+            cells: Grid::filled(width, height, (' ', None)),
+        }
+    }
+
+    /// Creates a frame of the grid, drawing each cell as the character and colour given by `f`.
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl Fn(&T) -> (char, Option<Rgb>)) -> Self {
+        Self { cells: grid.map(f) }
+    }
+
+    /// Writes the text along a row, starting at the given `(row, column)`. Text that runs off the
+    /// frame is cut off.
+    pub fn text(&mut self, (row, col): (usize, usize), text: &str, colour: Option<Rgb>) {
+        for (i, c) in text.chars().enumerate() {
+            if let Some(cell) = self.cells.get_mut((row, col + i)) {
+                *cell = (c, colour);
+            }
+        }
+    }
+
+    /// Recolours the given `(row, column)` cells. Cells outside the frame are ignored.
+    pub fn paint(&mut self, cells: impl IntoIterator<Item = (usize, usize)>, colour: Rgb) {
+        for cell in cells {
+            if let Some((_, old)) = self.cells.get_mut(cell) {
+                *old = Some(colour);
+            }
+        }
+    }
+
+    /// Writes the frame with ANSI escape codes for its colours, resetting to the default colour at
+    /// the end of each line.
+    pub fn write_ansi(&self, mut writer: impl Write) -> io::Result<()> {
+        for row in self.cells.rows() {
+            let mut line = String::new();
+            let mut current = None;
+            for &(c, colour) in row {
+                if colour != current {
+                    match colour {
+                        Some([r, g, b]) => write!(line, "\x1b[38;2;{r};{g};{b}m").unwrap(),
+                        None => line.push_str("\x1b[0m"),
+                    }
+                    current = colour;
+                }
+                line.push(c);
+            }
+            if current.is_some() {
+                line.push_str("\x1b[0m");
+            }
+            writeln!(writer, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the frame's characters without their colours.
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.cells.rows() {
+            writeln!(f, "{}", row.iter().map(|&(c, _)| c).collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+fn hex([r, g, b]: Rgb) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ppm() {
+        let grid = Grid::new(2, vec![true, false, false, true]);
+        let mut image = Image::from_grid(&grid, |&on| if on { [255; 3] } else { [0; 3] });
+        image.paint([(0, 1), (5, 5)], [255, 0, 0]);
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm, 2).unwrap();
+        let (header, pixels) = ppm.split_at(b"P6\n4 4\n255\n".len());
+        assert_eq!(header, b"P6\n4 4\n255\n");
+        let white = [255; 3];
+        let black = [0; 3];
+        let red = [255, 0, 0];
+        let rows = [[white, white, red, red], [black, black, white, white]];
+        let expected = [rows[0], rows[0], rows[1], rows[1]].concat().concat();
+        assert_eq!(pixels, expected);
+    }
+
+    #[test]
+    fn frame() {
+        let grid = Grid::new(3, vec![b'#', b'.', b'#']);
+        let mut frame = Frame::from_grid(&grid, |&b| (b as char, None));
+        frame.paint([(0, 1), (0, 2)], [255, 0, 0]);
+        assert_eq!(frame.to_string(), "#.#\n");
+        let mut ansi = Vec::new();
+        frame.write_ansi(&mut ansi).unwrap();
+        assert_eq!(ansi, b"#\x1b[38;2;255;0;0m.#\x1b[0m\n");
+
+        let mut frame = Frame::blank(4, 2);
+        frame.text((1, 1), "abcdef", Some([0, 0, 255]));
+        assert_eq!(frame.to_string(), "    \n abc\n");
+    }
+
+    #[test]
+    fn svg() {
+        let mut svg = Svg::default();
+        let square = [(0, 0), (100, 0), (100, 50), (0, 50)].map(|(x, y)| Point::new(x, y));
+        svg.polygon(&square, [255, 255, 255], [0, 0, 0]);
+        let mut document = Vec::new();
+        svg.write(&mut document, 220).unwrap();
+        let document = String::from_utf8(document).unwrap();
+        assert_eq!(
+            document.lines().collect::<Vec<_>>(),
+            [
+                r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-2 -2 104 54" width="220" height="114">"#,
+                r##"  <polygon points="0,0 100,0 100,50 0,50" fill="#ffffff" stroke="#000000" stroke-width="1" vector-effect="non-scaling-stroke"/>"##,
+                "</svg>",
+            ]
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//! Benchmarks parsing each day's real input, and solving each of its parts from the parsed puzzle.
+//!
+//! Run every day with `cargo bench -p aoc`, or only some of them by passing a filter, such as
+//! `cargo bench -p aoc -- day-16/`. Once the benchmarks have run, a table summarising the latest
+//! results for every day is printed.
+//!
+//! Day 5's part 2 takes minutes to run even once, so it's skipped unless `AOC_BENCH_SLOW` is set.
+
+use aoc_common::input::Source;
+use criterion::{black_box, Criterion, SamplingMode};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// The benchmarks that are only run when `AOC_BENCH_SLOW` is set.
+const SLOW: &[&str] = &["day-5/part_2"];
+
+/// Benchmarks parsing a day's input, and then solving each of its `parts`. Days whose parts take
+/// hundreds of milliseconds or more are given a smaller `sample_size`.
+macro_rules! bench_day {
+    ($c:expr, $number:literal, $krate:ident, bytes, $parts:tt $(, sample_size = $n:literal)?) => {
+        bench_day!(@ $c, $number, $krate, str::as_bytes, $parts $(, sample_size = $n)?)
+    };
+    ($c:expr, $number:literal, $krate:ident, $parts:tt $(, sample_size = $n:literal)?) => {
+        bench_day!(@ $c, $number, $krate, std::convert::identity, $parts $(, sample_size = $n)?)
+    };
+    (@ $c:expr, $number:literal, $krate:ident, $convert:expr, [$($part:ident),+] $(, sample_size = $n:literal)?) => {{
+        let input = $convert(read_input($number));
+        let mut group = $c.benchmark_group(concat!("day-", $number));
+        $(group.sampling_mode(SamplingMode::Flat).sample_size($n);)?
+        group.bench_function("parse", |b| b.iter(|| $krate::parse(black_box(input))));
+        let puzzle = $krate::parse(input).expect("input should be valid");
+        $(
+            let id = concat!("day-", $number, "/", stringify!($part));
+            if !SLOW.contains(&id) || env::var_os("AOC_BENCH_SLOW").is_some() {
+                group.bench_function(stringify!($part), |b| {
+                    b.iter(|| $krate::$part(black_box(&puzzle)))
+                });
+            }
+        )+
+        group.finish();
+        Summary {
+            day: $number,
+            benchmarks: &["parse", $(stringify!($part)),+],
+        }
+    }};
+}
+
+/// The benchmarks run for a single day, to be summarised once every day has been run.
+struct Summary {
+    day: u8,
+    benchmarks: &'static [&'static str],
+}
+
+fn main() {
+    let output_directory = output_directory();
+    let mut c = Criterion::default()
+        .output_directory(&output_directory)
+        .configure_from_args();
+    // Day 12 memoises its arrangements across calls, so after the first iteration its parts are
+    // mostly measuring cache hits.
+    let summaries = [
+        bench_day!(c, 1, day_1, bytes, [part_1, part_2]),
+        bench_day!(c, 2, day_2, [part_1, part_2]),
+        bench_day!(c, 3, day_3, bytes, [part_1, part_2]),
+        bench_day!(c, 4, day_4, [part_1, part_2]),
+        bench_day!(c, 5, day_5, [part_1, part_2], sample_size = 10),
+        bench_day!(c, 6, day_6, [part_1, part_2]),
+        bench_day!(c, 7, day_7, [part_1, part_2]),
+        bench_day!(c, 8, day_8, [part_1, part_2]),
+        bench_day!(c, 9, day_9, [part_1, part_2]),
+        bench_day!(c, 10, day_10, bytes, [part_1, part_2]),
+        bench_day!(c, 11, day_11, bytes, [part_1, part_2]),
+        bench_day!(c, 12, day_12, [part_1, part_2], sample_size = 10),
+        bench_day!(c, 13, day_13, [part_1, part_2]),
+        bench_day!(c, 14, day_14, [part_1, part_2]),
+        bench_day!(c, 15, day_15, bytes, [part_1, part_2]),
+        bench_day!(c, 16, day_16, bytes, [part_1, part_2], sample_size = 10),
+        bench_day!(c, 17, day_17, bytes, [part_1, part_2], sample_size = 10),
+        bench_day!(c, 18, day_18, [part_1, part_2]),
+        bench_day!(c, 19, day_19, [part_1, part_2]),
+        bench_day!(c, 20, day_20, [part_1, part_2]),
+        bench_day!(c, 21, day_21, [part_1]),
+        bench_day!(c, 22, day_22, [part_1, part_2], sample_size = 10),
+    ];
+    c.final_summary();
+    print_summary(&output_directory, &summaries);
+}
+
+/// Reads a day's real input, as the runner would.
+fn read_input(day: u8) -> &'static str {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(format!("../day-{day}/input.txt"))
+        .canonicalize()
+        .expect("input should exist");
+    let input = Source::Path(path)
+        .read("")
+        .expect("input should be readable");
+    input.into_owned().leak()
+}
+
+/// Where criterion writes its results: `target/criterion` in the workspace, unless the target
+/// directory has been moved.
+fn output_directory() -> PathBuf {
+    let target = env::var_os("CARGO_TARGET_DIR").map_or_else(
+        || Path::new(env!("CARGO_MANIFEST_DIR")).join("../target"),
+        PathBuf::from,
+    );
+    target.join("criterion")
+}
+
+/// Prints a table of the latest mean time of every benchmark, including those from earlier runs
+/// if they were filtered out of this one.
+fn print_summary(output_directory: &Path, summaries: &[Summary]) {
+    let columns = ["parse", "part_1", "part_2"];
+    println!("| day | {} |", columns.join(" | "));
+    println!("|----:|{}", "------:|".repeat(columns.len()));
+    for summary in summaries {
+        let cells = columns.map(|benchmark| {
+            if !summary.benchmarks.contains(&benchmark) {
+                return String::new();
+            }
+            let estimates = output_directory.join(format!(
+                "day-{}/{benchmark}/new/estimates.json",
+                summary.day
+            ));
+            read_mean(&estimates).map_or_else(|| "-".to_string(), format_nanos)
+        });
+        println!("| {} | {} |", summary.day, cells.join(" | "));
+    }
+}
+
+/// Reads the mean time, in nanoseconds, from a benchmark's `estimates.json`.
+fn read_mean(path: &Path) -> Option<f64> {
+    let estimates: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    estimates["mean"]["point_estimate"].as_f64()
+}
+
+fn format_nanos(nanos: f64) -> String {
+    match nanos {
+        n if n < 1e3 => format!("{n:.1} ns"),
+        n if n < 1e6 => format!("{:.1} µs", n / 1e3),
+        n if n < 1e9 => format!("{:.1} ms", n / 1e6),
+        n => format!("{:.2} s", n / 1e9),
+    }
+}
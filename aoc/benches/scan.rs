@@ -0,0 +1,103 @@
+//! Benchmarks the throughput of scanning day 1's calibration documents, on a generated document far
+//! bigger than the puzzle input, comparing the word-at-a-time scans with the byte-at-a-time ones.
+//!
+//! Run with `cargo bench -p aoc --bench scan`. The document is 256 MiB, unless `AOC_BENCH_MIB`
+//! sets another size.
+
+use criterion::{black_box, Criterion, SamplingMode, Throughput};
+use day_1::scan;
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// How many lines are generated at once, each batch from the next seed.
+const BATCH: usize = 100_000;
+
+fn main() {
+    let mib = env::var("AOC_BENCH_MIB").map_or(256, |mib| {
+        mib.parse::<usize>()
+            .expect("AOC_BENCH_MIB should be a whole number")
+    });
+    let input = generate(mib << 20);
+    let document = day_1::parse(&input).expect("generated input should be valid");
+
+    let mut c = Criterion::default()
+        .output_directory(&output_directory())
+        .configure_from_args();
+    let mut group = c.benchmark_group("day-1-scan");
+    group
+        .sampling_mode(SamplingMode::Flat)
+        .sample_size(10)
+        .throughput(Throughput::Bytes(input.len() as u64));
+
+    group.bench_function("lines/split", |b| {
+        b.iter(|| black_box(&input).split(|&b| b == b'\n').count())
+    });
+    group.bench_function("lines/memchr", |b| {
+        b.iter(|| scan::lines(black_box(&input)).count())
+    });
+    group.bench_function("digits/fallback", |b| {
+        b.iter(|| {
+            sum_digits(
+                &document,
+                scan::fallback::first_digit,
+                scan::fallback::last_digit,
+            )
+        })
+    });
+    group.bench_function("digits/words", |b| {
+        b.iter(|| sum_digits(&document, scan::first_digit, scan::last_digit))
+    });
+    group.bench_function("parse", |b| b.iter(|| day_1::parse(black_box(&input))));
+    group.bench_function("part_1/automaton", |b| {
+        let numerals = day_1::Vocabulary::numerals();
+        b.iter(|| day_1::calibrate(black_box(&document), &numerals))
+    });
+    group.bench_function("part_1/scan", |b| {
+        b.iter(|| day_1::calibrate_numerals(black_box(&document)))
+    });
+    group.finish();
+    c.final_summary();
+}
+
+/// Generates a calibration document of at least `bytes` bytes.
+fn generate(bytes: usize) -> Vec<u8> {
+    let mut input = Vec::with_capacity(bytes);
+    for seed in 0.. {
+        if input.len() >= bytes {
+            break;
+        }
+        if !input.is_empty() {
+            input.push(b'\n');
+        }
+        let batch = aoc_gen::generate(1, BATCH, seed).expect("day 1 has a generator");
+        input.extend_from_slice(batch.as_bytes());
+    }
+    input
+}
+
+/// Sums the first and last digits of every line, found with the given scans.
+fn sum_digits(
+    document: &day_1::Document,
+    first: fn(&[u8]) -> Option<usize>,
+    last: fn(&[u8]) -> Option<usize>,
+) -> u64 {
+    document
+        .lines
+        .iter()
+        .map(|line| {
+            let first = first(black_box(line)).map_or(0, |offset| line[offset]);
+            let last = last(black_box(line)).map_or(0, |offset| line[offset]);
+            u64::from(first) + u64::from(last)
+        })
+        .sum()
+}
+
+/// Where criterion writes its results: `target/criterion` in the workspace, unless the target
+/// directory has been moved.
+fn output_directory() -> PathBuf {
+    let target = env::var_os("CARGO_TARGET_DIR").map_or_else(
+        || Path::new(env!("CARGO_MANIFEST_DIR")).join("../target"),
+        PathBuf::from,
+    );
+    target.join("criterion")
+}
@@ -0,0 +1,305 @@
+use crate::params::Params;
+use aoc_common::parse::ParseError;
+use aoc_grid::geometry::Direction;
+use aoc_render::{Frame, Rgb};
+use day_20::ModuleKind;
+use std::collections::HashSet;
+use std::io::{self, Write};
+use std::iter;
+use std::thread;
+use std::time::Duration;
+
+const DIM: Rgb = [96, 96, 112];
+const HIGHLIGHT: Rgb = [255, 204, 0];
+const SECONDARY: Rgb = [0, 153, 204];
+/// The most cells a side view of day 22's bricks is wide or high. Any bricks beyond are cut off.
+const MAX_SIDE_VIEW: usize = 1000;
+
+/// The frames of an animation, drawn as they're played.
+pub type Frames<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+/// Parses a day's input and animates its simulation one step at a time, or returns `None` if the day
+/// isn't a simulation.
+pub fn frames<'a>(
+    day: u8,
+    input: &'a str,
+    params: &Params,
+) -> Option<Result<Frames<'a>, ParseError>> {
+    let frames = match day {
+        // Each spin cycle, until the rocks return to where they were after an earlier one or all
+        // the cycles have run.
+        14 => day_14::parse(input).map(|platform| {
+            let mut seen = HashSet::new();
+            let frames = iter::successors(Some(platform), |rocks| Some(day_14::spin_cycle(rocks)))
+                .take(params.day_14.cycles.saturating_add(1))
+                .take_while(move |rocks| seen.insert(rocks.clone()))
+                .map(|rocks| {
+                    Frame::from_grid(&rocks, |&b| match b {
+                        b'O' => ('O', Some(HIGHLIGHT)),
+                        b'#' => ('#', Some(DIM)),
+                        _ => ('.', Some(DIM)),
+                    })
+                });
+            Box::new(frames) as Frames
+        }),
+        // The beam entering the top-left tile heading right, spreading one tile at a time.
+        16 => day_16::parse(input.as_bytes()).map(|grid| {
+            let fronts = day_16::beam_fronts(&grid, Direction::Right, (0, 0));
+            let mut energized = Vec::new();
+            let frames = fronts.into_iter().map(move |front| {
+                let mut frame = Frame::from_grid(&grid, |&b| (b as char, Some(DIM)));
+                frame.paint(energized.iter().copied(), SECONDARY);
+                frame.paint(front.iter().copied(), HIGHLIGHT);
+                energized.extend(front);
+                frame
+            });
+            Box::new(frames) as Frames
+        }),
+        // The state of every module after each push of the button.
+        20 => day_20::parse(input).map(|mut configuration| {
+            // Stops early if the pulses from a push never stop.
+            let frames = (0..=params.day_20.presses).map_while(move |presses| {
+                if presses > 0 {
+                    day_20::press(&mut configuration)?;
+                }
+                Some(modules(&configuration, presses))
+            });
+            Box::new(frames) as Frames
+        }),
+        // The garden plots reachable after each step.
+        21 => day_21::parse(input).map(|garden| {
+            let background = Frame::from_grid(&garden.rocks, |&rock| {
+                if rock {
+                    ('#', Some(DIM))
+                } else {
+                    ('.', Some(DIM))
+                }
+            });
+            let start = HashSet::from([garden.start]);
+            let frames = iter::successors(Some(start), move |positions| {
+                Some(day_21::step(&garden.rocks, positions))
+            })
+            .take(params.day_21.steps.saturating_add(1))
+            .map(move |positions| {
+                let mut frame = background.clone();
+                for position in positions {
+                    frame.text(position, "O", Some(HIGHLIGHT));
+                }
+                frame
+            });
+            Box::new(frames) as Frames
+        }),
+        // The bricks seen from the side, settling one at a time from the lowest up.
+        22 => day_22::parse(input).map(|mut bricks| {
+            bricks.sort_by_key(|brick| brick.start.z);
+            let width = bricks
+                .iter()
+                .map(|brick| brick.end.x.saturating_add(1))
+                .max()
+                .unwrap_or(0)
+                .min(MAX_SIDE_VIEW);
+            let height = bricks
+                .iter()
+                .map(|brick| brick.end.z)
+                .max()
+                .unwrap_or(0)
+                .min(MAX_SIDE_VIEW);
+            let mut fallen = Vec::new();
+            let frames = (0..=bricks.len()).map(move |settled| {
+                if settled > 0 {
+                    fallen.push(day_22::land(&fallen, &bricks[settled - 1]));
+                }
+                side_view(&fallen, &bricks[settled..], (width, height))
+            });
+            Box::new(frames) as Frames
+        }),
+        _ => return None,
+    };
+    Some(frames)
+}
+
+/// Plays the frames in the terminal, replacing each with the next after `delay`.
+pub fn play(frames: Frames, mut writer: impl Write, delay: Duration) -> io::Result<()> {
+    for frame in frames {
+        // Clear the screen and move the cursor back to the top-left.
+        write!(writer, "\x1b[2J\x1b[H")?;
+        frame.write_ansi(&mut writer)?;
+        writer.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+/// Writes every frame as plain text, one after another without pausing, each beneath a line with
+/// its number.
+pub fn dump(frames: Frames, mut writer: impl Write) -> io::Result<()> {
+    for (i, frame) in frames.enumerate() {
+        writeln!(writer, "frame {i}")?;
+        write!(writer, "{frame}")?;
+    }
+    Ok(())
+}
+
+/// Draws a line for each module in alphabetical order: a flip-flop is followed by whether it's on,
+/// and a conjunction by the most recent pulse from each of its inputs, high or low.
+fn modules(configuration: &day_20::Configuration, presses: usize) -> Frame {
+    let mut names = configuration.keys().copied().collect::<Vec<_>>();
+    names.sort_unstable();
+    let width = configuration
+        .iter()
+        .map(|(name, module)| match &module.kind {
+            ModuleKind::Conjunction { memory } => name.len() + 2 + memory.len(),
+            ModuleKind::FlipFlop { .. } => name.len() + 2 + "off".len(),
+            ModuleKind::Broadcast => name.len(),
+        })
+        .chain([format!("press {presses}").len()])
+        .max()
+        .unwrap();
+    let mut frame = Frame::blank(width, names.len() + 1);
+    frame.text((0, 0), &format!("press {presses}"), None);
+    for (row, name) in (1..).zip(names) {
+        match &configuration[name].kind {
+            ModuleKind::Broadcast => frame.text((row, 0), name, None),
+            ModuleKind::FlipFlop { on } => {
+                let (state, colour) = if *on { ("on", HIGHLIGHT) } else { ("off", DIM) };
+                frame.text((row, 0), &format!("%{name}"), None);
+                frame.text((row, name.len() + 2), state, Some(colour));
+            }
+            ModuleKind::Conjunction { memory } => {
+                frame.text((row, 0), &format!("&{name}"), None);
+                let mut inputs = memory.iter().collect::<Vec<_>>();
+                inputs.sort_unstable();
+                for (i, (_, &high)) in inputs.into_iter().enumerate() {
+                    let (pulse, colour) = if high { ("H", HIGHLIGHT) } else { ("L", DIM) };
+                    frame.text((row, name.len() + 2 + i), pulse, Some(colour));
+                }
+            }
+        }
+    }
+    frame
+}
+
+/// Draws the bricks looking along the y axis, `width` wide and `height` high, with the ground at
+/// the bottom and any parts of bricks beyond cut off. Bricks that have settled are coloured
+/// differently to those still falling, and the last to settle is highlighted.
+fn side_view(
+    fallen: &[day_22::Brick],
+    falling: &[day_22::Brick],
+    (width, height): (usize, usize),
+) -> Frame {
+    let mut frame = Frame::blank(width, height + 1);
+    frame.text((height, 0), &"-".repeat(width), Some(DIM));
+    let last = fallen.len().checked_sub(1);
+    for (i, brick) in fallen.iter().chain(falling).enumerate() {
+        let colour = if Some(i) == last {
+            HIGHLIGHT
+        } else if i < fallen.len() {
+            SECONDARY
+        } else {
+            DIM
+        };
+        if brick.start.x >= width {
+            continue;
+        }
+        let cells = "#".repeat(brick.end.x.min(width - 1) - brick.start.x + 1);
+        for z in brick.start.z..=brick.end.z.min(height) {
+            frame.text((height - z, brick.start.x), &cells, Some(colour));
+        }
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dumped(day: u8, input: &str) -> String {
+        dumped_with(day, input, &Params::default())
+    }
+
+    fn dumped_with(day: u8, input: &str, params: &Params) -> String {
+        let mut dump = Vec::new();
+        let frames = frames(day, input, params).unwrap().unwrap();
+        super::dump(frames, &mut dump).unwrap();
+        String::from_utf8(dump).unwrap()
+    }
+
+    #[test]
+    fn spin_cycles() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let dump = dumped(14, input);
+        assert!(dump.starts_with(&format!("frame 0\n{input}\nframe 1\n.....#....\n")));
+        // The rocks repeat after ten cycles, starting from the third.
+        assert_eq!(dump.matches("frame").count(), 10);
+
+        let mut params = Params::default();
+        params.day_14.cycles = 2;
+        assert_eq!(dumped_with(14, input, &params).matches("frame").count(), 3);
+    }
+
+    #[test]
+    fn pulses() {
+        let input = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+        let dump = dumped(20, input);
+        let lines = dump.lines().map(str::trim_end).collect::<Vec<_>>();
+        assert_eq!(
+            lines[..14],
+            [
+                "frame 0",
+                "press 0",
+                "%a off",
+                "%b off",
+                "broadcaster",
+                "&con LL",
+                "&inv L",
+                "frame 1",
+                "press 1",
+                "%a on",
+                "%b on",
+                "broadcaster",
+                "&con HH",
+                "&inv H",
+            ]
+        );
+        assert_eq!(dump.matches("frame").count(), 1001);
+    }
+
+    #[test]
+    fn settling() {
+        let dump = dumped(22, "1,0,1~1,2,1\n0,0,3~2,0,3");
+        assert_eq!(
+            dump,
+            "frame 0\n###\n   \n # \n---\nframe 1\n###\n   \n # \n---\nframe 2\n   \n###\n # \n---\n"
+        );
+    }
+
+    #[test]
+    fn tall_bricks() {
+        let dump = dumped(22, "0,0,1~0,0,4000000000\n3000000000,0,1~3000000000,0,1");
+        let frame = dump.split("frame ").nth(1).unwrap();
+        assert_eq!(frame.lines().count(), MAX_SIDE_VIEW + 2);
+        assert!(frame
+            .lines()
+            .skip(1)
+            .all(|line| line.len() == MAX_SIDE_VIEW));
+    }
+
+    #[test]
+    fn no_animation() {
+        assert!(frames(1, "", &Params::default()).is_none());
+        assert!(frames(21, "x", &Params::default()).unwrap().is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+use toml::{Table, Value};
+
+/// The manifest of accepted answers, embedded into the binary at compile time.
+pub const MANIFEST: &str = include_str!("../../answers.toml");
+
+/// The accepted answer to each part of each day, keyed by day number and then part number.
+///
+/// The manifest is a TOML table per day, named `day-N`, with a `part-P` key for each part:
+///
+/// ```toml
+/// [day-6]
+/// part-1 = 288
+/// part-2 = 71503
+/// ```
+///
+/// Answers may be integers or strings.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<u8, BTreeMap<usize, String>>);
+
+impl Answers {
+    /// Parses a manifest of answers, reporting any key or value that doesn't fit the format.
+    pub fn parse(manifest: &str) -> Result<Self, String> {
+        let table: Table = manifest.parse().map_err(|err| format!("{err}"))?;
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in table {
+            let day = parse_key(&day_key, "day-")?;
+            let Value::Table(parts) = parts else {
+                return Err(format!("expected `{day_key}` to be a table"));
+            };
+            let parts = parts
+                .into_iter()
+                .map(|(part_key, answer)| {
+                    let part = parse_key(&part_key, "part-")?;
+                    let answer = match answer {
+                        Value::Integer(answer) => answer.to_string(),
+                        Value::String(answer) => answer,
+                        _ => {
+                            return Err(format!(
+                                "expected `{day_key}.{part_key}` to be an integer or a string"
+                            ))
+                        }
+                    };
+                    Ok((part, answer))
+                })
+                .collect::<Result<_, _>>()?;
+            answers.insert(day, parts);
+        }
+        Ok(Answers(answers))
+    }
+
+    /// Looks up the accepted answer to the given `part` of `day`, if one has been recorded.
+    pub fn get(&self, day: u8, part: usize) -> Option<&str> {
+        self.0.get(&day)?.get(&part).map(String::as_str)
+    }
+}
+
+fn parse_key<T: std::str::FromStr>(key: &str, prefix: &str) -> Result<T, String> {
+    key.strip_prefix(prefix)
+        .and_then(|number| number.parse().ok())
+        .ok_or_else(|| format!("expected a key of the form `{prefix}N`, found `{key}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(
+            "[day-6]
+part-1 = 288
+part-2 = \"71503\"",
+        )
+        .unwrap();
+        assert_eq!(answers.get(6, 1), Some("288"));
+        assert_eq!(answers.get(6, 2), Some("71503"));
+        assert_eq!(answers.get(6, 3), None);
+        assert_eq!(answers.get(7, 1), None);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Answers::parse("[day-x]").is_err());
+        assert!(Answers::parse("day-1 = 1").is_err());
+        assert!(Answers::parse("[day-1]\npart-1 = 1.5").is_err());
+        assert!(Answers::parse("[day-1]\nanswer = 1").is_err());
+    }
+
+    #[test]
+    fn manifest_covers_every_part() {
+        let answers = Answers::parse(MANIFEST).unwrap();
+        for day in DAYS {
+            for part in 1..=day.parts.len() {
+                assert!(
+                    answers.get(day.number, part).is_some(),
+                    "day {} part {part} has no answer",
+                    day.number
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+/// The answer to one part of a day, and how long it took to parse the input and solve the part.
+pub struct Entry {
+    pub day: u8,
+    pub part: usize,
+    pub answer: String,
+    pub time: Duration,
+}
+
+/// Formats the entries as a table, slowest first, marking every part that took longer than
+/// `part_budget`.
+pub fn table(entries: &[Entry], part_budget: Duration) -> String {
+    let mut sorted = entries.iter().collect::<Vec<_>>();
+    sorted.sort_by_key(|entry| std::cmp::Reverse(entry.time));
+    let times = sorted
+        .iter()
+        .map(|entry| format!("{:.2?}", entry.time))
+        .collect::<Vec<_>>();
+    let answer_width = sorted
+        .iter()
+        .map(|entry| entry.answer.len())
+        .chain(["answer".len()])
+        .max()
+        .unwrap();
+    let time_width = times
+        .iter()
+        .map(String::len)
+        .chain(["time".len()])
+        .max()
+        .unwrap();
+    let mut table = format!(
+        "day  part  {:answer_width$}  {:>time_width$}\n",
+        "answer", "time"
+    );
+    for (entry, time) in sorted.into_iter().zip(times) {
+        let row = format!(
+            "{:>3}  {:>4}  {:answer_width$}  {time:>time_width$}",
+            entry.day, entry.part, entry.answer
+        );
+        if entry.time > part_budget {
+            writeln!(table, "{row}  over budget").unwrap();
+        } else {
+            writeln!(table, "{row}").unwrap();
+        }
+    }
+    table
+}
+
+/// Parses a duration written as a number followed by a unit: `ns`, `us`, `ms`, `s` or `m`, such as
+/// `500ms` or `1.5s`.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .ok_or_else(|| format!("`{s}` has no unit, such as `ms` or `s`"))?;
+    let (number, unit) = s.split_at(split);
+    let number = number
+        .parse::<f64>()
+        .map_err(|_| format!("`{s}` doesn't start with a number"))?;
+    let seconds = match unit {
+        "ns" => 1e-9,
+        "us" | "µs" => 1e-6,
+        "ms" => 1e-3,
+        "s" => 1.0,
+        "m" => 60.0,
+        _ => {
+            return Err(format!(
+                "unknown unit `{unit}`, expected `ns`, `us`, `ms`, `s` or `m`"
+            ))
+        }
+    };
+    Duration::try_from_secs_f64(number * seconds).map_err(|err| format!("`{s}`: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("10m"), Ok(Duration::from_secs(600)));
+        assert_eq!(parse_duration("20us"), Ok(Duration::from_micros(20)));
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("ms").is_err());
+        assert!(parse_duration("10h").is_err());
+        assert!(parse_duration("99999999999999999999999m").is_err());
+    }
+
+    #[test]
+    fn slowest_first() {
+        let entry = |day, part, answer: &str, millis| Entry {
+            day,
+            part,
+            answer: answer.to_string(),
+            time: Duration::from_millis(millis),
+        };
+        let entries = [
+            entry(1, 1, "142", 2),
+            entry(5, 2, "46", 1500),
+            entry(22, 2, "98431", 800),
+        ];
+        assert_eq!(
+            table(&entries, Duration::from_secs(1)),
+            "\
+day  part  answer      time
+  5     2  46         1.50s  over budget
+ 22     2  98431   800.00ms
+  1     1  142       2.00ms
+"
+        );
+    }
+}
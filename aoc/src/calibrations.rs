@@ -0,0 +1,102 @@
+use day_1::{LineReport, Word};
+use serde::Serialize;
+use std::fmt::Write;
+
+/// A line's report, as printed by `--format json`.
+#[derive(Serialize)]
+pub struct Record {
+    line: usize,
+    first: Option<WordRecord>,
+    last: Option<WordRecord>,
+    value: u64,
+}
+
+#[derive(Serialize)]
+struct WordRecord {
+    word: String,
+    offset: usize,
+    value: u64,
+}
+
+impl From<&LineReport<'_>> for Record {
+    fn from(report: &LineReport) -> Self {
+        let word = |word: Word| WordRecord {
+            word: String::from_utf8_lossy(word.text).into_owned(),
+            offset: word.offset,
+            value: word.value,
+        };
+        Self {
+            line: report.line,
+            first: report.words.map(|(first, _)| word(first)),
+            last: report.words.map(|(_, last)| word(last)),
+            value: report.value,
+        }
+    }
+}
+
+/// Formats the reports as a table with a row for each line, giving the first and last words and
+/// their byte offsets as `word@offset`, followed by the value read from them. A line without any
+/// words has dashes in their place.
+pub fn table(reports: &[LineReport]) -> String {
+    let word = |word: Word| format!("{}@{}", String::from_utf8_lossy(word.text), word.offset);
+    let rows = reports
+        .iter()
+        .map(|report| {
+            let (first, last) = match report.words {
+                Some((first, last)) => (word(first), word(last)),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            (report.line, first, last, report.value)
+        })
+        .collect::<Vec<_>>();
+    let first_width = rows
+        .iter()
+        .map(|(_, first, _, _)| first.len())
+        .chain(["first".len()])
+        .max()
+        .unwrap();
+    let last_width = rows
+        .iter()
+        .map(|(_, _, last, _)| last.len())
+        .chain(["last".len()])
+        .max()
+        .unwrap();
+    let mut table = format!(
+        "line  {:first_width$}  {:last_width$}  value\n",
+        "first", "last"
+    );
+    for (line, first, last, value) in rows {
+        writeln!(
+            table,
+            "{line:>4}  {first:first_width$}  {last:last_width$}  {value:>5}"
+        )
+        .unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report() {
+        const INPUT: &[u8] = b"eightwothree\nxtwone3\n7";
+        let document = day_1::parse_with(INPUT, day_1::Policy::Zero).unwrap();
+        let reports = day_1::report(&document, &day_1::Vocabulary::numerals()).unwrap();
+        assert_eq!(
+            table(&reports),
+            "\
+line  first  last  value
+   1  -      -         0
+   2  3@6    3@6      33
+   3  7@0    7@0      77
+"
+        );
+        let reports = day_1::report(&document, &day_1::Vocabulary::english()).unwrap();
+        assert_eq!(
+            serde_json::to_string(&Record::from(&reports[1])).unwrap(),
+            r#"{"line":2,"first":{"word":"two","offset":1,"value":2},"last":{"word":"3","offset":6,"value":3},"value":23}"#
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::params::Params;
+use aoc_common::parse::ParseError;
+use std::time::{Duration, Instant};
+
+/// A single day of the calendar, along with its embedded puzzle input and the solutions to each of
+/// its parts.
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    pub parts: &'static [Part],
+}
+
+/// Parses a day's input and solves one of its parts with the given parameters, timing each step.
+pub type Part = fn(&str, &Params) -> Result<Solution, ParseError>;
+
+/// The answer to one part of a day, formatted as a string, and how long it took to find.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub answer: String,
+    /// How long it took to parse the input.
+    pub parse_time: Duration,
+    /// How long it took to solve the part from the parsed input.
+    pub solve_time: Duration,
+}
+
+impl Day {
+    /// Solves the given `part` (1-indexed) of this day, returning `None` if the part doesn't exist.
+    pub fn solve(&self, part: usize, input: &str) -> Option<Result<Solution, ParseError>> {
+        self.solve_with(part, input, &Params::default())
+    }
+
+    /// Solves the given `part` (1-indexed) of this day with the given parameters, rather than the
+    /// puzzle's, returning `None` if the part doesn't exist.
+    pub fn solve_with(
+        &self,
+        part: usize,
+        input: &str,
+        params: &Params,
+    ) -> Option<Result<Solution, ParseError>> {
+        let run = self.parts.get(part.checked_sub(1)?)?;
+        Some(run(input, params))
+    }
+}
+
+/// An answer to one part of a day, which may not exist for some inputs, or may only be found to be
+/// malformed once solving.
+trait Answer {
+    /// Formats the answer, or `none` if there isn't one.
+    fn answer(self) -> Result<String, ParseError>;
+}
+
+macro_rules! answer {
+    ($($ty:ty),+) => {
+        $(impl Answer for $ty {
+            fn answer(self) -> Result<String, ParseError> {
+                Ok(self.to_string())
+            }
+        })+
+    };
+}
+
+answer!(i32, u64, usize);
+
+impl<T: Answer> Answer for Option<T> {
+    fn answer(self) -> Result<String, ParseError> {
+        self.map_or_else(|| Ok("none".to_string()), T::answer)
+    }
+}
+
+impl<T: Answer> Answer for Result<T, ParseError> {
+    fn answer(self) -> Result<String, ParseError> {
+        self?.answer()
+    }
+}
+
+/// Finds the day with the given `number`, if it has been solved.
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Adds a day, given its crate, whether it takes its input as bytes, and its parts. A part followed
+/// by a field of [`Params`] in brackets, such as `part_2_with(day_11)`, is passed that field. A day
+/// given `parse_with(day_2)` before its parts is parsed with that field too.
+macro_rules! day {
+    ($number:literal, $krate:ident, bytes, $parts:tt) => {
+        day!(@ $number, $krate, str::as_bytes, $parts)
+    };
+    ($number:literal, $krate:ident, parse_with($parse_field:ident), $parts:tt) => {
+        day!(@ $number, $krate, std::convert::identity, ($parse_field), $parts)
+    };
+    ($number:literal, $krate:ident, $parts:tt) => {
+        day!(@ $number, $krate, std::convert::identity, $parts)
+    };
+    (@ $number:literal, $krate:ident, $convert:expr, [$($part:ident $(($field:ident))?),+]) => {
+        day!(@ $number, $krate, $convert, (), [$($part $(($field))?),+])
+    };
+    (@ $number:literal, $krate:ident, $convert:expr, $parse:tt, [$($part:ident $(($field:ident))?),+]) => {
+        Day {
+            number: $number,
+            input: include_str!(concat!("../../day-", $number, "/input.txt")),
+            parts: &[$(|input, params| {
+                let start = Instant::now();
+                let puzzle = day!(@parse $krate, $convert(input), params, $parse)?;
+                let parse_time = start.elapsed();
+                let start = Instant::now();
+                let answer = day!(@call $krate::$part, &puzzle, params $(, $field)?).answer()?;
+                let solve_time = start.elapsed();
+                Ok(Solution { answer, parse_time, solve_time })
+            }),+],
+        }
+    };
+    (@parse $krate:ident, $input:expr, $params:ident, ()) => {
+        $krate::parse($input)
+    };
+    (@parse $krate:ident, $input:expr, $params:ident, ($field:ident)) => {
+        $krate::parse_with($input, &$params.$field)
+    };
+    (@call $part:path, $puzzle:expr, $params:ident) => {{
+        let _ = $params;
+        $part($puzzle)
+    }};
+    (@call $part:path, $puzzle:expr, $params:ident, $field:ident) => {
+        $part($puzzle, &$params.$field)
+    };
+}
+
+pub static DAYS: &[Day] = &[
+    day!(1, day_1, bytes, [part_1, part_2]),
+    day!(2, day_2, parse_with(day_2), [part_1_with(day_2), part_2]),
+    day!(3, day_3, bytes, [part_1, part_2]),
+    day!(4, day_4, [part_1, part_2]),
+    day!(5, day_5, [part_1, part_2]),
+    day!(6, day_6, [part_1, part_2]),
+    day!(7, day_7, [part_1, part_2]),
+    day!(8, day_8, [part_1, part_2]),
+    day!(9, day_9, [part_1, part_2]),
+    day!(10, day_10, bytes, [part_1, part_2]),
+    day!(11, day_11, bytes, [part_1, part_2_with(day_11)]),
+    day!(12, day_12, [part_1, part_2_with(day_12)]),
+    day!(13, day_13, [part_1, part_2]),
+    day!(14, day_14, [part_1, part_2_with(day_14)]),
+    day!(15, day_15, bytes, [part_1, part_2]),
+    day!(16, day_16, bytes, [part_1, part_2]),
+    day!(
+        17,
+        day_17,
+        bytes,
+        [part_1_with(day_17), part_2_with(day_17)]
+    ),
+    day!(18, day_18, [part_1, part_2]),
+    day!(19, day_19, [part_1, part_2]),
+    day!(20, day_20, [part_1_with(day_20), part_2]),
+    day!(21, day_21, [part_1_with(day_21)]),
+    day!(22, day_22, [part_1, part_2]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_in_order() {
+        for (idx, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.number as usize, idx + 1);
+        }
+    }
+
+    #[test]
+    fn solve() {
+        let day = find(6).unwrap();
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+        assert_eq!(day.solve(1, input).unwrap().unwrap().answer, "288");
+        assert_eq!(day.solve(2, input).unwrap().unwrap().answer, "71503");
+        assert_eq!(day.solve(0, input), None);
+        assert_eq!(day.solve(3, input), None);
+    }
+
+    #[test]
+    fn solve_with() {
+        let day = find(21).unwrap();
+        let mut params = Params::default();
+        params.day_21.steps = 0;
+        assert_eq!(
+            day.solve_with(1, day.input, &params)
+                .unwrap()
+                .unwrap()
+                .answer,
+            "1"
+        );
+    }
+
+    #[test]
+    fn undeclared_colour() {
+        let day = find(2).unwrap();
+        let input = "Game 1: 3 blue, 4 purple";
+        let err = day.solve(1, input).unwrap().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 19, "purple"));
+        let mut params = Params::default();
+        params.day_2.cubes.insert("purple".to_string(), 4);
+        let solve = |part| day.solve_with(part, input, &params).unwrap().unwrap();
+        assert_eq!(solve(1).answer, "1");
+        assert_eq!(solve(2).answer, "12");
+    }
+
+    #[test]
+    fn malformed_when_solving() {
+        let day = find(1).unwrap();
+        let input = "1abc2\nthree";
+        let err = day.solve(1, input).unwrap().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "three"));
+        assert_eq!(day.solve(2, input).unwrap().unwrap().answer, "45");
+    }
+
+    #[test]
+    fn no_answer() {
+        let day = find(17).unwrap();
+        let mut params = Params::default();
+        params.day_17.ultra_crucible = day_17::Limits { min: 200, max: 300 };
+        let solve = |part| day.solve_with(part, day.input, &params).unwrap().unwrap();
+        assert_eq!(solve(2).answer, "none");
+        assert_ne!(solve(1).answer, "none");
+    }
+
+    #[test]
+    fn malformed_input() {
+        let day = find(6).unwrap();
+        let err = day.solve(1, "Time: 7\nDistance: x").unwrap().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 11, "x"));
+    }
+
+    #[test]
+    fn unsolved_day() {
+        assert!(find(25).is_none());
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The site puzzle inputs are fetched from, unless another is given.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const YEAR: u16 = 2023;
+
+/// Downloads puzzle inputs, caching each one on disk so that it's only ever fetched once.
+///
+/// Inputs are cached as `day-N/input.txt` under the cache directory, so with the workspace root as
+/// the cache directory they're written exactly where each day expects its committed input.
+pub struct Fetcher {
+    /// The site to fetch inputs from, without a trailing slash.
+    pub base_url: String,
+    /// The session token to authenticate with. Only needed when an input isn't already cached.
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+}
+
+/// Where a fetched input came from.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already cached at this path.
+    Cached(PathBuf),
+    /// The input was downloaded to this path.
+    Downloaded(PathBuf),
+}
+
+impl Fetcher {
+    /// The path a day's input is cached at.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day-{day}")).join("input.txt")
+    }
+
+    /// Fetches a day's input, downloading it unless it's already cached. An empty input file, such
+    /// as the placeholder written by `aoc new-day`, doesn't count as cached.
+    pub fn fetch(&self, day: u8) -> Result<Fetched, FetchError> {
+        let path = self.path(day);
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+        let session = self.session.as_deref().ok_or(FetchError::MissingSession)?;
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let input = ureq::get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", "advent-of-code-2023 input fetcher")
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, _) => FetchError::Status(status),
+                ureq::Error::Transport(err) => FetchError::Transport(err.to_string()),
+            })?
+            .into_string()
+            .map_err(|err| FetchError::Io(url, err))?;
+        write(&path, &input).map_err(|err| FetchError::Io(path.display().to_string(), err))?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+/// Writes the input to a temporary file before moving it into place, so that a failed write is
+/// never mistaken for a cached input.
+fn write(path: &Path, input: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("txt.partial");
+    fs::write(&partial, input)?;
+    fs::rename(&partial, path)
+}
+
+/// An error encountered while fetching a puzzle input.
+#[derive(Debug)]
+pub enum FetchError {
+    /// The input isn't cached, and there's no session token to download it with.
+    MissingSession,
+    /// The server responded with an unsuccessful status code.
+    Status(u16),
+    /// The server couldn't be reached.
+    Transport(String),
+    /// The input couldn't be read from the response, or written to the cache.
+    Io(String, io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => {
+                write!(f, "a session token is required to download the input")
+            }
+            FetchError::Status(status) => write!(f, "the server responded with status {status}"),
+            FetchError::Transport(err) => write!(f, "{err}"),
+            FetchError::Io(name, err) => write!(f, "{name}: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Starts a server on a random local port that answers every request with `status` and `body`,
+    /// recording the request line and cookie of each request it receives.
+    fn stub_server(status: &'static str, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = Vec::new();
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if !line.contains(':') || line.to_lowercase().starts_with("cookie:") {
+                        request.push(line);
+                    }
+                }
+                recorded.lock().unwrap().push(request.join("\n"));
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (base_url, requests)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once() {
+        let (base_url, requests) = stub_server("200 OK", "1 2 3\n");
+        let fetcher = Fetcher {
+            base_url,
+            session: Some("abc".to_string()),
+            cache_dir: cache_dir("downloads_once"),
+        };
+        let path = fetcher.path(5);
+        assert_eq!(fetcher.fetch(5).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        assert_eq!(fetcher.fetch(5).unwrap(), Fetched::Cached(path));
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /2023/day/5/input HTTP/1.1\nCookie: session=abc"]
+        );
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn unsuccessful_status() {
+        let (base_url, _) = stub_server("404 Not Found", "not found");
+        let fetcher = Fetcher {
+            base_url,
+            session: Some("abc".to_string()),
+            cache_dir: cache_dir("unsuccessful_status"),
+        };
+        assert!(matches!(fetcher.fetch(1), Err(FetchError::Status(404))));
+        assert!(!fetcher.path(1).exists());
+    }
+
+    #[test]
+    fn missing_session() {
+        let fetcher = Fetcher {
+            base_url: "http://127.0.0.1:1".to_string(),
+            session: None,
+            cache_dir: cache_dir("missing_session"),
+        };
+        assert!(matches!(fetcher.fetch(1), Err(FetchError::MissingSession)));
+        write(&fetcher.path(1), "").unwrap();
+        assert!(matches!(fetcher.fetch(1), Err(FetchError::MissingSession)));
+        // Cached inputs don't need a session.
+        write(&fetcher.path(1), "cached").unwrap();
+        assert_eq!(fetcher.fetch(1).unwrap(), Fetched::Cached(fetcher.path(1)));
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+}
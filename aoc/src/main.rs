@@ -0,0 +1,675 @@
+use answers::Answers;
+use aoc_common::input::Source;
+use budget::Entry;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use days::{Day, Solution};
+use fetch::{Fetched, Fetcher};
+use params::ParamArgs;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+use timings::Timings;
+use tracing::info_span;
+use tracing_subscriber::layer::SubscriberExt;
+
+mod animate;
+mod answers;
+mod budget;
+mod calibrations;
+mod days;
+mod fetch;
+mod params;
+mod render;
+mod scaffold;
+mod timings;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a single day, or every day, printing one answer per line.
+    Run(RunArgs),
+    /// Solves every day against its embedded input, checking each answer against `answers.toml`.
+    Verify(VerifyArgs),
+    /// Downloads a day's puzzle input, unless it has already been downloaded.
+    Fetch(FetchArgs),
+    /// Creates a new day's crate from a template and adds it to the workspace.
+    NewDay(NewDayArgs),
+    /// Generates a random input for a day, of any size, and prints it.
+    Generate(GenerateArgs),
+    /// Solves every day against its embedded input, printing a table of answers and times, slowest
+    /// first, and checks the times against a budget.
+    Report(ReportArgs),
+    /// Solves a part of a grid day, drawing what it finds, such as a loop or a path, as an image.
+    Render(RenderArgs),
+    /// Reads day 1's calibration document, printing which words each line's value was read from.
+    Calibrations(CalibrationsArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The day to solve.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+    day: Option<u8>,
+    /// The part of the day to solve. Both parts are solved if omitted.
+    #[arg(long, requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Solve every day.
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Read the day's input from this file, or from stdin if `-`, instead of the embedded input.
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+    /// How to print each answer.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Once finished, print a report to stderr of how long was spent in each traced span, such as
+    /// parsing, solving each part, and the inner phases of some solutions.
+    #[arg(long)]
+    timings: bool,
+    /// Before solving, play the day's simulation in the terminal one step at a time: day 14's spin
+    /// cycles, 16's beam, 20's pulses, 21's steps or 22's falling bricks. The frames are written to
+    /// stdout, so can't be mixed with another format of answers.
+    #[arg(long, requires = "day", conflicts_with_all = ["all", "format"])]
+    animate: bool,
+    /// How long to show each frame of the animation for, such as `50ms`.
+    #[arg(long, requires = "animate", value_parser = budget::parse_duration, default_value = "100ms")]
+    frame_delay: Duration,
+    /// Print each frame of the animation as plain text, without colours or pausing, rather than
+    /// playing it.
+    #[arg(long, requires = "animate")]
+    dump_frames: bool,
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// A line of the form `day N part P: ANSWER`.
+    Text,
+    /// A JSON object per line, with the day, part, answer, and parse and solve times in
+    /// nanoseconds.
+    Json,
+}
+
+/// A single answer, as printed by `--format json`.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: usize,
+    answer: &'a str,
+    parse_time_ns: u128,
+    solve_time_ns: u128,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Check against the answers in this file, instead of the embedded `answers.toml`.
+    #[arg(long)]
+    answers: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// The day to fetch the input for.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// The session token to authenticate with, from the `session` cookie of a logged in browser.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// The directory inputs are cached in, as `day-N/input.txt`.
+    #[arg(long, default_value = ".")]
+    cache_dir: PathBuf,
+    /// The site to download inputs from.
+    #[arg(long, default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+#[derive(Args)]
+struct NewDayArgs {
+    /// The day to create.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// The puzzle's title, for the crate's documentation.
+    #[arg(long, default_value = "TODO")]
+    title: String,
+    /// The root of the workspace to create the day in.
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+    /// Download the day's input once the crate has been created.
+    #[arg(long)]
+    fetch: bool,
+    /// The session token to download the input with. Ignored without `--fetch`.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// The day to generate an input for.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// The size of the input. What this measures depends on the day, such as the number of lines,
+    /// or the side of a grid.
+    #[arg(long)]
+    size: usize,
+    /// The seed to generate the input from. The same seed and size always generate the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+#[derive(Args)]
+struct ReportArgs {
+    /// Mark every part that takes longer than this, such as `500ms` or `2s`.
+    #[arg(long, value_parser = budget::parse_duration, default_value = "1s")]
+    part_budget: Duration,
+    /// Fail if every part together takes longer than this. Day 5's part 2 alone takes several
+    /// minutes.
+    #[arg(long, value_parser = budget::parse_duration, default_value = "10m")]
+    total_budget: Duration,
+}
+
+#[derive(Args)]
+struct RenderArgs {
+    /// The day to render: 10, 14, 16, 17, 18 or 21.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// The part of the day to render.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Read the day's input from this file, or from stdin if `-`, instead of the embedded input.
+    #[arg(long)]
+    input: Option<String>,
+    /// The file to write to. Defaults to `day-N-part-P` with the extension of the format, `.ppm`
+    /// for grids, or `.svg` for day 18's outline.
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// The number of pixels along each side of a grid's cells.
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+    /// The number of pixels along the longest side of an SVG.
+    #[arg(long, default_value_t = 800)]
+    size: usize,
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+#[derive(Args)]
+struct CalibrationsArgs {
+    /// The part whose vocabulary to read the lines with: only numeric digits in part 1, or also
+    /// spelled out digits in part 2.
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Read the document from this file, or from stdin if `-`, instead of the embedded input.
+    #[arg(long)]
+    input: Option<String>,
+    /// How to print the report: a table, or a JSON object per line.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// What to do with lines that have no digits.
+    #[arg(long, value_enum, default_value_t = Policy::Error)]
+    policy: Policy,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Policy {
+    /// Leave the line out of the report.
+    Skip,
+    /// Read the line's calibration value as 0.
+    Zero,
+    /// Reject the document, reporting the line.
+    Error,
+}
+
+impl From<Policy> for day_1::Policy {
+    fn from(policy: Policy) -> Self {
+        match policy {
+            Policy::Skip => day_1::Policy::Skip,
+            Policy::Zero => day_1::Policy::Zero,
+            Policy::Error => day_1::Policy::Error,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Fetch(args) => fetch(args),
+        Command::NewDay(args) => new_day(args),
+        Command::Generate(args) => generate(args),
+        Command::Report(args) => report(args),
+        Command::Render(args) => render(args),
+        Command::Calibrations(args) => calibrations(args),
+    }
+}
+
+/// Selects the given day, or every day if `None`, reporting an error if the day hasn't been solved.
+fn select_days(number: Option<u8>) -> Option<Vec<&'static Day>> {
+    match number {
+        Some(number) => match days::find(number) {
+            Some(day) => Some(vec![day]),
+            None => {
+                eprintln!("error: day {number} has not been solved");
+                None
+            }
+        },
+        None => Some(days::DAYS.iter().collect()),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let Some(days) = select_days(args.day) else {
+        return ExitCode::FAILURE;
+    };
+    let numbers = days.iter().map(|day| day.number).collect::<Vec<_>>();
+    let params = match args.params.params(&numbers) {
+        Ok(params) => params,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let timings = Timings::default();
+    if args.timings {
+        let subscriber = tracing_subscriber::registry().with(timings.clone());
+        tracing::subscriber::set_global_default(subscriber).expect("no other subscriber is set");
+    }
+    let source = Source::from_arg(args.input.as_deref());
+    for day in days {
+        let _span = info_span!("day", number = day.number).entered();
+        let input = match source.read(day.input) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: failed to read {source}: {err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        if args.animate {
+            let frames = match animate::frames(day.number, &input, &params) {
+                Some(Ok(frames)) => frames,
+                Some(Err(err)) => {
+                    eprintln!("error: {}", err.render(&input, source_name(&source, day)));
+                    return ExitCode::FAILURE;
+                }
+                None => {
+                    eprintln!("error: day {} has no animation", day.number);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let stdout = std::io::stdout().lock();
+            let played = if args.dump_frames {
+                animate::dump(frames, stdout)
+            } else {
+                animate::play(frames, stdout, args.frame_delay)
+            };
+            if let Err(err) = played {
+                eprintln!("error: failed to write the animation: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        let parts = match args.part {
+            Some(part) => vec![part as usize],
+            None => (1..=day.parts.len()).collect(),
+        };
+        for part in parts {
+            match day.solve_with(part, &input, &params) {
+                Some(Ok(solution)) => print_solution(args.format, day, part, &solution),
+                Some(Err(err)) => {
+                    eprintln!("error: {}", err.render(&input, source_name(&source, day)));
+                    return ExitCode::FAILURE;
+                }
+                None => {
+                    eprintln!("error: day {} has no part {part}", day.number);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+    if args.timings {
+        eprint!("{timings}");
+    }
+    ExitCode::SUCCESS
+}
+
+/// Names the source of a day's input in error messages.
+fn source_name(source: &Source, day: &Day) -> String {
+    match source {
+        Source::Embedded => format!("day-{}/input.txt", day.number),
+        source => source.to_string(),
+    }
+}
+
+fn print_solution(format: Format, day: &Day, part: usize, solution: &Solution) {
+    match format {
+        Format::Text => println!("day {} part {part}: {}", day.number, solution.answer),
+        Format::Json => {
+            let record = Record {
+                day: day.number,
+                part,
+                answer: &solution.answer,
+                parse_time_ns: solution.parse_time.as_nanos(),
+                solve_time_ns: solution.solve_time.as_nanos(),
+            };
+            println!("{}", serde_json::to_string(&record).unwrap());
+        }
+    }
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let Some(days) = select_days(args.day) else {
+        return ExitCode::FAILURE;
+    };
+    let manifest = match &args.answers {
+        Some(path) => match std::fs::read_to_string(path) {
+            Ok(manifest) => manifest,
+            Err(err) => {
+                eprintln!("error: failed to read {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => answers::MANIFEST.to_string(),
+    };
+    let answers = match Answers::parse(&manifest) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: invalid answers manifest: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut total = Duration::ZERO;
+    for day in days {
+        let input = Source::Embedded
+            .read(day.input)
+            .expect("embedded input is always readable");
+        for part in 1..=day.parts.len() {
+            let result = day.solve(part, &input).expect("part exists");
+            let elapsed = result.as_ref().map_or(Duration::ZERO, |solution| {
+                solution.parse_time + solution.solve_time
+            });
+            total += elapsed;
+            let expected = answers.get(day.number, part);
+            let outcome = match (result.map(|solution| solution.answer), expected) {
+                (Ok(answer), Some(expected)) if answer == expected => {
+                    passed += 1;
+                    println!("day {} part {part}: ok ({elapsed:.2?})", day.number);
+                    continue;
+                }
+                (Ok(answer), Some(expected)) => {
+                    format!("expected {expected}, got {answer}")
+                }
+                (Ok(answer), None) => format!("no answer recorded, got {answer}"),
+                (Err(err), _) => {
+                    let name = format!("day-{}/input.txt", day.number);
+                    format!("failed to parse input: {}", err.render(&input, name))
+                }
+            };
+            failed += 1;
+            println!(
+                "day {} part {part}: FAILED: {outcome} ({elapsed:.2?})",
+                day.number
+            );
+        }
+    }
+    println!("{passed} passed, {failed} failed in {total:.2?}");
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn fetch(args: FetchArgs) -> ExitCode {
+    let fetcher = Fetcher {
+        base_url: args.base_url.trim_end_matches('/').to_string(),
+        session: args.session.filter(|session| !session.is_empty()),
+        cache_dir: args.cache_dir,
+    };
+    match fetcher.fetch(args.day) {
+        Ok(Fetched::Cached(path)) => {
+            println!("day {}: already cached at {}", args.day, path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("day {}: downloaded to {}", args.day, path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: failed to fetch day {}: {err}", args.day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(args: NewDayArgs) -> ExitCode {
+    if let Err(err) = scaffold::new_day(&args.root, args.day, &args.title) {
+        eprintln!("error: failed to create day {}: {err}", args.day);
+        return ExitCode::FAILURE;
+    }
+    println!(
+        "day {}: created {}",
+        args.day,
+        args.root.join(format!("day-{}", args.day)).display()
+    );
+    if !args.fetch {
+        return ExitCode::SUCCESS;
+    }
+    fetch(FetchArgs {
+        day: args.day,
+        session: args.session,
+        cache_dir: args.root,
+        base_url: fetch::DEFAULT_BASE_URL.to_string(),
+    })
+}
+
+fn generate(args: GenerateArgs) -> ExitCode {
+    match aoc_gen::generate(args.day, args.size, args.seed) {
+        Some(input) => {
+            println!("{input}");
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("error: day {} has no generator", args.day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn report(args: ReportArgs) -> ExitCode {
+    let mut entries = Vec::new();
+    for day in days::DAYS {
+        let input = Source::Embedded
+            .read(day.input)
+            .expect("embedded input is always readable");
+        for part in 1..=day.parts.len() {
+            match day.solve(part, &input).expect("part exists") {
+                Ok(solution) => entries.push(Entry {
+                    day: day.number,
+                    part,
+                    answer: solution.answer,
+                    time: solution.parse_time + solution.solve_time,
+                }),
+                Err(err) => {
+                    let name = format!("day-{}/input.txt", day.number);
+                    eprintln!("error: {}", err.render(&input, name));
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+    print!("{}", budget::table(&entries, args.part_budget));
+    let total = entries.iter().map(|entry| entry.time).sum::<Duration>();
+    let over = entries
+        .iter()
+        .filter(|entry| entry.time > args.part_budget)
+        .count();
+    println!(
+        "{} parts in {total:.2?}, {over} over the per-part budget of {:?}",
+        entries.len(),
+        args.part_budget
+    );
+    if total > args.total_budget {
+        eprintln!(
+            "error: took {total:.2?}, over the total budget of {:?}",
+            args.total_budget
+        );
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn render(args: RenderArgs) -> ExitCode {
+    let Some(day) = days::find(args.day) else {
+        eprintln!("error: day {} has not been solved", args.day);
+        return ExitCode::FAILURE;
+    };
+    let source = Source::from_arg(args.input.as_deref());
+    let input = match source.read(day.input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: failed to read {source}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let params = match args.params.params(&[day.number]) {
+        Ok(params) => params,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let picture = match render::render(day.number, args.part as usize, &input, &params) {
+        Some(Ok(picture)) => picture,
+        Some(Err(err)) => {
+            eprintln!("error: {}", err.render(&input, source_name(&source, day)));
+            return ExitCode::FAILURE;
+        }
+        None => {
+            eprintln!(
+                "error: day {} part {} can't be rendered",
+                day.number, args.part
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+    let output = args.output.unwrap_or_else(|| {
+        PathBuf::from(format!(
+            "day-{}-part-{}.{}",
+            day.number,
+            args.part,
+            picture.extension()
+        ))
+    });
+    let written = File::create(&output).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        picture.write(&mut writer, args.scale, args.size)?;
+        writer.flush()
+    });
+    if let Err(err) = written {
+        eprintln!("error: failed to write {}: {err}", output.display());
+        return ExitCode::FAILURE;
+    }
+    println!(
+        "day {} part {}: rendered to {}",
+        day.number,
+        args.part,
+        output.display()
+    );
+    ExitCode::SUCCESS
+}
+
+fn calibrations(args: CalibrationsArgs) -> ExitCode {
+    let day = days::find(1).expect("day 1 has been solved");
+    let source = Source::from_arg(args.input.as_deref());
+    let input = match source.read(day.input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: failed to read {source}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let document = match day_1::parse_with(input.as_bytes(), args.policy.into()) {
+        Ok(document) => document,
+        Err(err) => {
+            eprintln!("error: {}", err.render(&input, source_name(&source, day)));
+            return ExitCode::FAILURE;
+        }
+    };
+    let vocabulary = match args.part {
+        1 => day_1::Vocabulary::numerals(),
+        _ => day_1::Vocabulary::english(),
+    };
+    let reports = match day_1::report(&document, &vocabulary) {
+        Ok(reports) => reports,
+        Err(err) => {
+            eprintln!("error: {}", err.render(&input, source_name(&source, day)));
+            return ExitCode::FAILURE;
+        }
+    };
+    match args.format {
+        Format::Text => print!("{}", calibrations::table(&reports)),
+        Format::Json => {
+            for report in &reports {
+                let record = calibrations::Record::from(report);
+                println!("{}", serde_json::to_string(&record).unwrap());
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::error::ErrorKind;
+
+    #[test]
+    fn session_without_fetch() {
+        // A session is usually set in the environment, which clap treats as if it were passed.
+        let cli = Cli::try_parse_from(["aoc", "new-day", "--day", "23", "--session", "abc"]);
+        assert!(matches!(
+            cli.map(|cli| cli.command),
+            Ok(Command::NewDay(NewDayArgs { fetch: false, .. }))
+        ));
+    }
+
+    #[test]
+    fn no_expansion() {
+        let run = |expansion| {
+            Cli::try_parse_from(["aoc", "run", "--day", "11", "--expansion", expansion])
+        };
+        assert!(run("1").is_ok());
+        assert!(run("0").is_err());
+    }
+
+    #[test]
+    fn animation_flags() {
+        let run = |args: &[&str]| {
+            Cli::try_parse_from(["aoc", "run", "--day", "14"].iter().chain(args))
+                .map_err(|err| err.kind())
+        };
+        assert!(run(&["--animate", "--frame-delay", "10ms"]).is_ok());
+        assert_eq!(
+            run(&["--frame-delay", "10ms"]).err(),
+            Some(ErrorKind::MissingRequiredArgument)
+        );
+        assert_eq!(
+            run(&["--animate", "--format", "json"]).err(),
+            Some(ErrorKind::ArgumentConflict)
+        );
+    }
+}
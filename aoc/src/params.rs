@@ -0,0 +1,199 @@
+use clap::builder::RangedU64ValueParser;
+use clap::Args;
+use day_17::Limits;
+
+/// The parameters of every day that has any, such as how many steps to take. Each defaults to the
+/// value set by the puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    pub day_2: day_2::Params,
+    pub day_11: day_11::Params,
+    pub day_12: day_12::Params,
+    pub day_14: day_14::Params,
+    pub day_17: day_17::Params,
+    pub day_20: day_20::Params,
+    pub day_21: day_21::Params,
+}
+
+/// Flags overriding the parameters set by the puzzles. Each only applies to a single day.
+#[derive(Args, Default)]
+#[command(next_help_heading = "Puzzle parameters")]
+pub struct ParamArgs {
+    /// Day 2: a colour of cube in the bag and how many there are, which may be repeated. Given any,
+    /// the bag only holds the colours given [default: red=12, green=13, blue=14]
+    #[arg(long = "cube", value_name = "COLOUR=COUNT", value_parser = parse_cube)]
+    cubes: Vec<(String, usize)>,
+    /// Day 2: the number of red cubes in the bag [default: 12]
+    #[arg(long)]
+    red_cubes: Option<usize>,
+    /// Day 2: the number of green cubes in the bag [default: 13]
+    #[arg(long)]
+    green_cubes: Option<usize>,
+    /// Day 2: the number of blue cubes in the bag [default: 14]
+    #[arg(long)]
+    blue_cubes: Option<usize>,
+    /// Day 11: how many times bigger each empty row and column is in part 2 [default: 1000000]
+    #[arg(long, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    expansion: Option<usize>,
+    /// Day 12: how many copies of itself each row unfolds into in part 2 [default: 5]
+    #[arg(long)]
+    copies: Option<usize>,
+    /// Day 14: how many spin cycles to run in part 2 [default: 1000000000]
+    #[arg(long)]
+    cycles: Option<usize>,
+    /// Day 17: the fewest and most blocks the crucible moves before turning in part 1
+    /// [default: 1-3]
+    #[arg(long, value_name = "MIN-MAX", value_parser = parse_limits)]
+    crucible_moves: Option<Limits>,
+    /// Day 17: the fewest and most blocks the ultra crucible moves before turning in part 2
+    /// [default: 4-10]
+    #[arg(long, value_name = "MIN-MAX", value_parser = parse_limits)]
+    ultra_crucible_moves: Option<Limits>,
+    /// Day 20: how many times to push the button in part 1 [default: 1000]
+    #[arg(long)]
+    presses: Option<usize>,
+    /// Day 21: how many steps to take in part 1 [default: 64]
+    #[arg(long)]
+    steps: Option<usize>,
+}
+
+impl ParamArgs {
+    /// Overrides the puzzles' parameters with those given, checking that each applies to one of
+    /// the days being solved.
+    pub fn params(&self, days: &[u8]) -> Result<Params, String> {
+        let flags = [
+            ("--cube", 2, !self.cubes.is_empty()),
+            ("--red-cubes", 2, self.red_cubes.is_some()),
+            ("--green-cubes", 2, self.green_cubes.is_some()),
+            ("--blue-cubes", 2, self.blue_cubes.is_some()),
+            ("--expansion", 11, self.expansion.is_some()),
+            ("--copies", 12, self.copies.is_some()),
+            ("--cycles", 14, self.cycles.is_some()),
+            ("--crucible-moves", 17, self.crucible_moves.is_some()),
+            (
+                "--ultra-crucible-moves",
+                17,
+                self.ultra_crucible_moves.is_some(),
+            ),
+            ("--presses", 20, self.presses.is_some()),
+            ("--steps", 21, self.steps.is_some()),
+        ];
+        for (flag, day, given) in flags {
+            if given && !days.contains(&day) {
+                return Err(format!("{flag} only applies to day {day}"));
+            }
+        }
+        let mut params = Params::default();
+        let bag = &mut params.day_2.cubes;
+        if !self.cubes.is_empty() {
+            bag.clone_from(&self.cubes.iter().cloned().collect());
+        }
+        let cubes = [
+            ("red", self.red_cubes),
+            ("green", self.green_cubes),
+            ("blue", self.blue_cubes),
+        ];
+        for (colour, count) in cubes {
+            if let Some(count) = count {
+                bag.insert(colour.to_string(), count);
+            }
+        }
+        let day_11 = &mut params.day_11;
+        day_11.expansion = self.expansion.unwrap_or(day_11.expansion);
+        let day_12 = &mut params.day_12;
+        day_12.copies = self.copies.unwrap_or(day_12.copies);
+        let day_14 = &mut params.day_14;
+        day_14.cycles = self.cycles.unwrap_or(day_14.cycles);
+        let day_17 = &mut params.day_17;
+        day_17.crucible = self.crucible_moves.unwrap_or(day_17.crucible);
+        day_17.ultra_crucible = self.ultra_crucible_moves.unwrap_or(day_17.ultra_crucible);
+        let day_20 = &mut params.day_20;
+        day_20.presses = self.presses.unwrap_or(day_20.presses);
+        let day_21 = &mut params.day_21;
+        day_21.steps = self.steps.unwrap_or(day_21.steps);
+        Ok(params)
+    }
+}
+
+/// Parses a colour of cube and how many of them there are, written as `COLOUR=COUNT`, such as
+/// `purple=3`.
+fn parse_cube(s: &str) -> Result<(String, usize), String> {
+    let (colour, count) = s
+        .split_once('=')
+        .ok_or_else(|| format!("`{s}` isn't of the form `COLOUR=COUNT`"))?;
+    if colour.is_empty() || colour.contains(char::is_whitespace) {
+        return Err(format!("`{colour}` isn't a colour"));
+    }
+    let count = count
+        .parse()
+        .map_err(|_| format!("`{count}` isn't a whole number"))?;
+    Ok((colour.to_string(), count))
+}
+
+/// Parses the limits on a crucible's moves, written as `MIN-MAX`, such as `4-10`.
+fn parse_limits(s: &str) -> Result<Limits, String> {
+    let (min, max) = s
+        .split_once('-')
+        .ok_or_else(|| format!("`{s}` isn't of the form `MIN-MAX`"))?;
+    let number = |n: &str| {
+        n.parse::<usize>()
+            .map_err(|_| format!("`{n}` isn't a whole number"))
+    };
+    let (min, max) = (number(min)?, number(max)?);
+    if min == 0 || min > max {
+        return Err(format!(
+            "a crucible must move at least one block, and no more than its maximum, got `{s}`"
+        ));
+    }
+    Ok(Limits { min, max })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides() {
+        let args = ParamArgs {
+            expansion: Some(10),
+            crucible_moves: Some(Limits { min: 2, max: 5 }),
+            ..ParamArgs::default()
+        };
+        let params = args.params(&[11, 17]).unwrap();
+        assert_eq!(params.day_11.expansion, 10);
+        assert_eq!(params.day_17.crucible, Limits { min: 2, max: 5 });
+        assert_eq!(params.day_17.ultra_crucible, Limits { min: 4, max: 10 });
+        assert_eq!(params.day_21, day_21::Params::default());
+        assert_eq!(
+            args.params(&[11]),
+            Err("--crucible-moves only applies to day 17".to_string())
+        );
+    }
+
+    #[test]
+    fn bag() {
+        let args = ParamArgs {
+            cubes: vec![("purple".to_string(), 3), ("red".to_string(), 1)],
+            red_cubes: Some(2),
+            ..ParamArgs::default()
+        };
+        let bag = args.params(&[2]).unwrap().day_2.cubes;
+        assert_eq!(
+            bag.into_iter().collect::<Vec<_>>(),
+            [("purple".to_string(), 3), ("red".to_string(), 2)]
+        );
+        assert_eq!(parse_cube("purple=3"), Ok(("purple".to_string(), 3)));
+        assert!(parse_cube("purple").is_err());
+        assert!(parse_cube("=3").is_err());
+        assert!(parse_cube("purple=x").is_err());
+    }
+
+    #[test]
+    fn limits() {
+        assert_eq!(parse_limits("4-10"), Ok(Limits { min: 4, max: 10 }));
+        assert!(parse_limits("4").is_err());
+        assert!(parse_limits("0-3").is_err());
+        assert!(parse_limits("5-4").is_err());
+        assert!(parse_limits("a-4").is_err());
+    }
+}
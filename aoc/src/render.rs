@@ -0,0 +1,166 @@
+use crate::params::Params;
+use aoc_common::parse::ParseError;
+use aoc_grid::geometry::Direction;
+use aoc_render::{Image, Rgb, Svg};
+use std::io::{self, Write};
+
+const BACKGROUND: Rgb = [16, 16, 32];
+const WALL: Rgb = [96, 96, 112];
+const HIGHLIGHT: Rgb = [255, 204, 0];
+const SECONDARY: Rgb = [0, 153, 204];
+
+/// A rendering of one part of a day.
+pub enum Picture {
+    Image(Image),
+    Svg(Svg),
+}
+
+impl Picture {
+    /// The extension of the file format the picture is written in.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Picture::Image(_) => "ppm",
+            Picture::Svg(_) => "svg",
+        }
+    }
+
+    /// Writes the picture, drawing each cell of an image as `scale` pixels square, or fitting an
+    /// SVG into `size` pixels.
+    pub fn write(&self, writer: impl Write, scale: usize, size: usize) -> io::Result<()> {
+        match self {
+            Picture::Image(image) => image.write_ppm(writer, scale),
+            Picture::Svg(svg) => svg.write(writer, size),
+        }
+    }
+}
+
+/// Parses a day's input and renders what solving the given part with the given parameters finds,
+/// or returns `None` if there's no rendering of the part.
+pub fn render(
+    day: u8,
+    part: usize,
+    input: &str,
+    params: &Params,
+) -> Option<Result<Picture, ParseError>> {
+    let picture = match (day, part) {
+        // The loop, and the tiles it encloses.
+        (10, 1 | 2) => day_10::parse(input.as_bytes()).map(|sketch| {
+            let mut image = Image::from_grid(&sketch.tiles, |_| BACKGROUND);
+            image.paint(day_10::main_loop(&sketch), HIGHLIGHT);
+            image.paint(day_10::enclosed(&sketch), SECONDARY);
+            Picture::Image(image)
+        }),
+        // The rounded rocks, once they've rolled north.
+        (14, 1) => day_14::parse(input).map(|platform| {
+            let tilted = day_14::tilt_north(&platform);
+            Picture::Image(Image::from_grid(&tilted, |&b| match b {
+                b'O' => HIGHLIGHT,
+                b'#' => WALL,
+                _ => BACKGROUND,
+            }))
+        }),
+        // The tiles energized by the beam entering the top-left tile heading right.
+        (16, 1) => day_16::parse(input.as_bytes()).map(|grid| {
+            let mut image = Image::from_grid(&grid, |&b| if b == b'.' { BACKGROUND } else { WALL });
+            let energized = day_16::energized(&grid, Direction::Right, (0, 0));
+            image.paint(energized, HIGHLIGHT);
+            Picture::Image(image)
+        }),
+        // The path of least heat loss, if there is one, over the heat lost in each block.
+        (17, 1 | 2) => day_17::parse(input.as_bytes()).map(|map| {
+            let mut image = Image::from_grid(&map, |&heat_loss| [heat_loss * 16; 3]);
+            let path = match part {
+                1 => day_17::path_1(&map, &params.day_17),
+                _ => day_17::path_2(&map, &params.day_17),
+            };
+            image.paint(path.unwrap_or_default(), HIGHLIGHT);
+            Picture::Image(image)
+        }),
+        // The outline of the lagoon.
+        (18, 1 | 2) => day_18::parse(input).map(|plan| {
+            let corners = match part {
+                1 => day_18::corners_1(&plan),
+                _ => day_18::corners_2(&plan),
+            };
+            let mut svg = Svg::default();
+            svg.polygon(&corners, SECONDARY, HIGHLIGHT);
+            Picture::Svg(svg)
+        }),
+        // The garden plots reachable in exactly the given number of steps.
+        (21, 1) => day_21::parse(input).map(|garden| {
+            let mut image =
+                Image::from_grid(&garden.rocks, |&rock| if rock { WALL } else { BACKGROUND });
+            image.paint(day_21::reachable(&garden, params.day_21.steps), HIGHLIGHT);
+            Picture::Image(image)
+        }),
+        _ => return None,
+    };
+    Some(picture)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(day: u8, part: usize, input: &str) -> Option<Result<Picture, ParseError>> {
+        super::render(day, part, input, &Params::default())
+    }
+
+    fn write(picture: &Picture) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        picture.write(&mut bytes, 2, 100).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn main_loop() {
+        let picture = render(10, 1, "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF")
+            .unwrap()
+            .unwrap();
+        assert_eq!(picture.extension(), "ppm");
+        let ppm = write(&picture);
+        let (header, pixels) = ppm.split_at(b"P6\n10 10\n255\n".len());
+        assert_eq!(header, b"P6\n10 10\n255\n");
+        // The loop runs through the second row from the second column to the fourth.
+        let row = &pixels[2 * 10 * 3..3 * 10 * 3];
+        assert_eq!(
+            row[..8 * 3],
+            [&[BACKGROUND; 2][..], &[HIGHLIGHT; 6]].concat().concat()
+        );
+    }
+
+    #[test]
+    fn outline() {
+        let picture = render(
+            18,
+            1,
+            "R 2 (#000000)\nD 2 (#000000)\nL 2 (#000000)\nU 2 (#000000)",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(picture.extension(), "svg");
+        let svg = String::from_utf8(write(&picture)).unwrap();
+        assert!(svg.contains(r#"points="2,0 2,2 0,2 0,0""#), "{svg}");
+    }
+
+    #[test]
+    fn params() {
+        let mut params = Params::default();
+        params.day_21.steps = 1;
+        let picture = super::render(21, 1, "...\n.S.\n...", &params)
+            .unwrap()
+            .unwrap();
+        let ppm = write(&picture);
+        let pixels = &ppm[b"P6\n6 6\n255\n".len()..];
+        // One step from the start reaches the middle of the top row, but not its corners.
+        assert_eq!(pixels[..3], BACKGROUND);
+        assert_eq!(pixels[2 * 3..3 * 3], HIGHLIGHT);
+    }
+
+    #[test]
+    fn unsupported() {
+        assert!(render(1, 1, "").is_none());
+        assert!(render(21, 2, "").is_none());
+        assert!(render(10, 1, "x").unwrap().is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml");
+const MAIN_RS: &str = include_str!("../templates/day/src/main.rs");
+const LIB_RS: &str = include_str!("../templates/day/src/lib.rs");
+
+/// Creates the `day-N` crate under the workspace `root` from the template in `aoc/templates/day`,
+/// with an empty `input.txt`, and adds it to the workspace's members.
+///
+/// Fails without changing anything if the crate's directory already exists.
+pub fn new_day(root: &Path, day: u8, title: &str) -> io::Result<()> {
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = add_member(&fs::read_to_string(&manifest_path)?, day)?;
+    let dir = root.join(format!("day-{day}"));
+    fs::create_dir(&dir).map_err(|err| match err.kind() {
+        io::ErrorKind::AlreadyExists => {
+            io::Error::new(err.kind(), format!("{} already exists", dir.display()))
+        }
+        _ => err,
+    })?;
+    fs::create_dir(dir.join("src"))?;
+    let render = |template: &str| {
+        template
+            .replace("{{day}}", &day.to_string())
+            .replace("{{title}}", title)
+    };
+    fs::write(dir.join("Cargo.toml"), render(CARGO_TOML))?;
+    fs::write(dir.join("src/main.rs"), render(MAIN_RS))?;
+    fs::write(dir.join("src/lib.rs"), render(LIB_RS))?;
+    fs::write(dir.join("input.txt"), "")?;
+    fs::write(manifest_path, manifest)
+}
+
+/// Inserts `day-N` into the `members` list of a workspace manifest, before the first day that
+/// comes after it, keeping the list's formatting of one member per line. Does nothing if it's
+/// already a member.
+fn add_member(manifest: &str, day: u8) -> io::Result<String> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "no workspace members list");
+    let member = format!("    'day-{day}',");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "members = [")
+        .ok_or_else(invalid)?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "]")
+            .ok_or_else(invalid)?;
+    if lines[start..end].contains(&member.as_str()) {
+        return Ok(manifest.to_string());
+    }
+    let insert_at = (start + 1..end)
+        .find(|&idx| member_day(lines[idx]).is_some_and(|number| number > day))
+        .unwrap_or(end);
+    lines.insert(insert_at, &member);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn member_day(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("'day-")?
+        .strip_suffix("',")?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[workspace]
+
+members = [
+    'aoc',
+    'day-1',
+    'day-3',
+]
+
+resolver = \"2\"
+";
+
+    #[test]
+    fn add_member_in_order() {
+        assert_eq!(
+            add_member(MANIFEST, 2).unwrap(),
+            MANIFEST.replace("    'day-3',", "    'day-2',\n    'day-3',")
+        );
+        assert_eq!(
+            add_member(MANIFEST, 4).unwrap(),
+            MANIFEST.replace("    'day-3',", "    'day-3',\n    'day-4',")
+        );
+        assert_eq!(add_member(MANIFEST, 3).unwrap(), MANIFEST);
+        assert!(add_member("[workspace]", 1).is_err());
+    }
+
+    #[test]
+    fn new_day_from_template() {
+        let root = std::env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        new_day(&root, 23, "A Long Walk").unwrap();
+        let lib = fs::read_to_string(root.join("day-23/src/lib.rs")).unwrap();
+        assert!(lib.starts_with("//! Day 23: A Long Walk\n"));
+        let main = fs::read_to_string(root.join("day-23/src/main.rs")).unwrap();
+        assert!(main.contains("day_23::parse(&input)"));
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("    'day-23',\n]"));
+        let err = new_day(&root, 23, "A Long Walk").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
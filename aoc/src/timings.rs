@@ -0,0 +1,161 @@
+use std::fmt::{self, Debug, Display, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// A tracing layer that times every span, from when it's created until it's closed, gathering the
+/// timings into a tree with a node for each distinct path of spans from the root.
+///
+/// Spans with the same path, such as every call to the same instrumented function from the same
+/// caller, are added together. Displaying the timings prints the tree as a report.
+#[derive(Clone, Default)]
+pub struct Timings {
+    root: Arc<Mutex<Node>>,
+}
+
+#[derive(Default)]
+struct Node {
+    /// The span's name, followed by the values of its fields.
+    label: String,
+    total: Duration,
+    calls: usize,
+    /// The spans created within this one, in the order they were first created.
+    children: Vec<Node>,
+}
+
+/// The label and start time of a span, kept in the span's extensions until it's closed.
+struct Timing {
+    label: String,
+    start: Instant,
+}
+
+impl<S> Layer<S> for Timings
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("the span has just been created");
+        let mut label = Label(span.name().to_string());
+        attrs.record(&mut label);
+        span.extensions_mut().insert(Timing {
+            label: label.0,
+            start: Instant::now(),
+        });
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(scope) = ctx.span_scope(&id) else {
+            return;
+        };
+        let mut elapsed = Duration::ZERO;
+        let mut path = Vec::new();
+        // The scope ends with the span being closed, so this leaves `elapsed` as its time.
+        for span in scope.from_root() {
+            if let Some(timing) = span.extensions().get::<Timing>() {
+                elapsed = timing.start.elapsed();
+                path.push(timing.label.clone());
+            }
+        }
+        let mut root = self.root.lock().unwrap();
+        let node = path.into_iter().fold(&mut *root, Node::child);
+        node.total += elapsed;
+        node.calls += 1;
+    }
+}
+
+impl Node {
+    /// Finds the child with the given label, adding it if there isn't one yet.
+    fn child(&mut self, label: String) -> &mut Node {
+        let idx = match self.children.iter().position(|child| child.label == label) {
+            Some(idx) => idx,
+            None => {
+                self.children.push(Node {
+                    label,
+                    ..Node::default()
+                });
+                self.children.len() - 1
+            }
+        };
+        &mut self.children[idx]
+    }
+
+    /// Lists this node's descendants depth first, along with their depths.
+    fn descendants<'a>(&'a self, depth: usize, nodes: &mut Vec<(usize, &'a Node)>) {
+        for child in &self.children {
+            nodes.push((depth, child));
+            child.descendants(depth + 1, nodes);
+        }
+    }
+}
+
+/// Prints each span on its own line, indented beneath the span it was created in, followed by its
+/// total time and, if there was more than one, the number of spans that were added together.
+impl Display for Timings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let root = self.root.lock().unwrap();
+        let mut nodes = Vec::new();
+        root.descendants(0, &mut nodes);
+        let width = nodes
+            .iter()
+            .map(|(depth, node)| depth * 2 + node.label.len())
+            .max()
+            .unwrap_or(0);
+        for (depth, node) in nodes {
+            let label = format!("{:indent$}{}", "", node.label, indent = depth * 2);
+            write!(f, "{label:width$}  {:>10.2?}", node.total)?;
+            if node.calls > 1 {
+                write!(f, "  ({} calls)", node.calls)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Appends the value of each of a span's fields to its label.
+struct Label(String);
+
+impl Visit for Label {
+    fn record_str(&mut self, _field: &Field, value: &str) {
+        self.0.push(' ');
+        self.0.push_str(value);
+    }
+
+    fn record_debug(&mut self, _field: &Field, value: &dyn Debug) {
+        self.0.push_str(&format!(" {value:?}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::info_span;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn report() {
+        let timings = Timings::default();
+        let subscriber = tracing_subscriber::registry().with(timings.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            let _day = info_span!("day", number = 14).entered();
+            info_span!("parse").in_scope(|| {});
+            info_span!("part_2").in_scope(|| {
+                for _ in 0..3 {
+                    info_span!("cycle").in_scope(|| {});
+                }
+            });
+        });
+        let report = timings.to_string();
+        let lines = report.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4, "{report}");
+        assert!(lines[0].starts_with("day 14 "));
+        assert!(lines[1].starts_with("  parse "));
+        assert!(lines[2].starts_with("  part_2 ") && !lines[2].ends_with("calls)"));
+        assert!(lines[3].starts_with("    cycle ") && lines[3].ends_with("  (3 calls)"));
+    }
+}
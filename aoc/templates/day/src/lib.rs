@@ -0,0 +1,45 @@
+//! Day {{day}}: {{title}}
+
+use aoc_common::parse::ParseError;
+
+/// Parses the puzzle input into its lines.
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(input.lines().collect())
+}
+
+/// Solves part 1.
+pub fn part_1(lines: &[&str]) -> usize {
+    todo!("solve part 1 for {} lines", lines.len())
+}
+
+/// Solves part 2.
+pub fn part_2(lines: &[&str]) -> usize {
+    todo!("solve part 2 for {} lines", lines.len())
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<usize, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<usize, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(0));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(0));
+    }
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let input = aoc_common::input::from_args(include_str!("../input.txt"));
+    let puzzle = day_{{day}}::parse(&input).unwrap_or_else(|err| aoc_common::parse::exit(&err, &input));
+    println!("{}", day_{{day}}::part_1(&puzzle));
+    println!("{}", day_{{day}}::part_2(&puzzle));
+}
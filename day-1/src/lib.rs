@@ -0,0 +1,416 @@
+//! Day 1: Trebuchet?!
+//!
+//! Recovers the calibration values hidden in each line of a calibration document, where each value
+//! is formed from the first and last digit on its line.
+
+use aho_corasick::{AhoCorasick, Match};
+use aoc_common::parse::ParseError;
+use once_cell::sync::Lazy;
+use std::cmp::{self, Reverse};
+use tracing::instrument;
+
+pub mod scan;
+
+static ENGLISH: Lazy<Vocabulary> = Lazy::new(Vocabulary::english);
+
+/// A calibration document, one line of text per calibration value.
+pub struct Document<'a> {
+    /// The input the lines were read from, to locate errors in.
+    pub input: &'a [u8],
+    pub lines: Vec<&'a [u8]>,
+    /// What to do with lines that have no digits.
+    pub policy: Policy,
+}
+
+/// What to do with a line that has no digits, such as a blank line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Policy {
+    /// Leave the line out.
+    Skip,
+    /// Read the line's calibration value as 0.
+    Zero,
+    /// Reject the document, with an error locating the line.
+    #[default]
+    Error,
+}
+
+/// Splits the puzzle input into the lines of a calibration [`Document`], rejecting any line without
+/// digits once the document is read.
+#[instrument(skip_all)]
+pub fn parse(input: &[u8]) -> Result<Document<'_>, ParseError> {
+    parse_with(input, Policy::Error)
+}
+
+/// Splits the input into the lines of a calibration [`Document`], with the given policy for lines
+/// without any digits. Lines may end with either `\n` or `\r\n`, and the last line may end with a
+/// line ending too.
+///
+/// Which lines have digits depends on the vocabulary they're read with, so the policy is only
+/// applied once the document is read, by [`calibrate`], [`calibrate_numerals`] or [`report`].
+pub fn parse_with(input: &[u8], policy: Policy) -> Result<Document<'_>, ParseError> {
+    let mut lines = scan::lines(input)
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .collect::<Vec<_>>();
+    if input.ends_with(b"\n") {
+        lines.pop();
+    }
+    Ok(Document {
+        input,
+        lines,
+        policy,
+    })
+}
+
+/// Sums the calibration values, reading only numeric digits. A line with only spelled out digits is
+/// an error if the document's policy is to reject lines without digits.
+#[instrument(skip_all)]
+pub fn part_1(document: &Document) -> Result<u64, ParseError> {
+    calibrate_numerals(document)
+}
+
+/// Sums the calibration values, reading both numeric digits and spelled out digits, such as "one".
+#[instrument(skip_all)]
+pub fn part_2(document: &Document) -> Result<u64, ParseError> {
+    calibrate(document, &ENGLISH)
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &[u8]) -> Result<u64, ParseError> {
+    part_1(&parse(input)?)
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &[u8]) -> Result<u64, ParseError> {
+    part_2(&parse(input)?)
+}
+
+/// Sums the calibration values, reading the numbers in the given vocabulary. Each value is the first
+/// digit on its line times ten, plus the last, where a number's digits are those it's written with:
+/// "twenty" starts with a 2 and ends with a 0.
+///
+/// Lines without any words from the vocabulary are handled by the document's [`Policy`]: skipped
+/// lines and lines read as 0 add nothing to the sum, and otherwise the first is returned as an
+/// error.
+pub fn calibrate(document: &Document, vocabulary: &Vocabulary) -> Result<u64, ParseError> {
+    Ok(report(document, vocabulary)?
+        .into_iter()
+        .map(|report| report.value)
+        .sum())
+}
+
+/// Sums the calibration values, reading only numeric digits. This gives the same result as
+/// [`calibrate`] with the numerals, but finds the digits with the faster [`scan`]s.
+pub fn calibrate_numerals(document: &Document) -> Result<u64, ParseError> {
+    document
+        .lines
+        .iter()
+        .map(|line| {
+            let digit = |offset: usize| u64::from(line[offset] - b'0');
+            match (scan::first_digit(line), scan::last_digit(line)) {
+                (Some(first), Some(last)) => Ok(digit(first) * 10 + digit(last)),
+                _ => match document.policy {
+                    Policy::Skip | Policy::Zero => Ok(0),
+                    Policy::Error => Err(missing_digit(document, line)),
+                },
+            }
+        })
+        .sum()
+}
+
+/// Reads every line of the document with the given vocabulary, reporting which words its
+/// calibration value was read from. Lines without any words are handled by the document's
+/// [`Policy`]: they're left out, reported with no words and a value of 0, or the first is returned
+/// as an error.
+pub fn report<'a>(
+    document: &Document<'a>,
+    vocabulary: &Vocabulary,
+) -> Result<Vec<LineReport<'a>>, ParseError> {
+    let mut reports = Vec::with_capacity(document.lines.len());
+    for (i, line) in document.lines.iter().enumerate() {
+        let words = vocabulary.first_and_last_words(line);
+        let value = match (words, document.policy) {
+            (Some((first, last)), _) => leading_digit(first.value) * 10 + last.value % 10,
+            (None, Policy::Skip) => continue,
+            (None, Policy::Zero) => 0,
+            (None, Policy::Error) => return Err(missing_digit(document, line)),
+        };
+        reports.push(LineReport {
+            line: i + 1,
+            words,
+            value,
+        });
+    }
+    Ok(reports)
+}
+
+/// The first digit of `value`, written in decimal.
+fn leading_digit(value: u64) -> u64 {
+    value / 10u64.pow(value.checked_ilog10().unwrap_or(0))
+}
+
+/// The error for a line of the document without any digits.
+fn missing_digit(document: &Document, line: &[u8]) -> ParseError {
+    ParseError::new(document.input, line, "expected a digit")
+}
+
+/// The words that stand for numbers, such as "7", "seven" or "twenty", and the value each stands
+/// for.
+///
+/// Words may overlap, as in "eightwo", which reads as 8 and then 2.
+pub struct Vocabulary {
+    automaton: AhoCorasick,
+    values: Vec<u64>,
+}
+
+impl Vocabulary {
+    /// Creates a vocabulary of the given words and the numbers they stand for.
+    ///
+    /// # Panics
+    ///
+    /// If a word is empty.
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u64)>) -> Self {
+        let (words, values): (Vec<_>, Vec<_>) = words.into_iter().unzip();
+        assert!(
+            words.iter().all(|word| !word.is_empty()),
+            "words must not be empty"
+        );
+        Self {
+            automaton: AhoCorasick::new(words).expect("the vocabulary is small enough to build"),
+            values,
+        }
+    }
+
+    /// The numeric digits, `0` to `9`.
+    pub fn numerals() -> Self {
+        Self::new(NUMERAL_WORDS.into_iter().zip(0..))
+    }
+
+    /// The numeric digits, along with the English words for one to nine.
+    pub fn english() -> Self {
+        let words = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        Self::new(
+            NUMERAL_WORDS
+                .into_iter()
+                .zip(0..)
+                .chain(words.into_iter().zip(1..)),
+        )
+    }
+
+    /// Finds the values of the first and last words on the line, or `None` if there are none.
+    pub fn first_and_last(&self, line: &[u8]) -> Option<(u64, u64)> {
+        let (first, last) = self.first_and_last_words(line)?;
+        Some((first.value, last.value))
+    }
+
+    /// Finds the first and last words on the line, in a single pass, or `None` if there are none.
+    /// Of the words starting at the same place, the longest is taken.
+    pub fn first_and_last_words<'a>(&self, line: &'a [u8]) -> Option<(Word<'a>, Word<'a>)> {
+        // Overlapping matches are found in order of where they end, rather than where they start.
+        let mut matches = self.automaton.find_overlapping_iter(line);
+        let found = matches.next()?;
+        let (first, last) = matches.fold((found, found), |(first, last), found| {
+            (
+                cmp::min_by_key(first, found, |m| (m.start(), Reverse(m.len()))),
+                cmp::max_by_key(last, found, |m| (m.start(), m.len())),
+            )
+        });
+        let word = |m: Match| Word {
+            text: &line[m.range()],
+            offset: m.start(),
+            value: self.values[m.pattern().as_usize()],
+        };
+        Some((word(first), word(last)))
+    }
+}
+
+/// A word read from a line of the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Word<'a> {
+    pub text: &'a [u8],
+    /// The byte offset of the word from the start of its line.
+    pub offset: usize,
+    /// The value of the number the word stands for.
+    pub value: u64,
+}
+
+/// How the calibration value on a single line was read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineReport<'a> {
+    /// The line's number, starting from 1.
+    pub line: usize,
+    /// The first and last words on the line, which may be the same word, or `None` if the line has
+    /// no words from the vocabulary.
+    pub words: Option<(Word<'a>, Word<'a>)>,
+    /// The calibration value read from the line: its first digit times ten, plus its last, or 0 for
+    /// a line without words.
+    pub value: u64,
+}
+
+const NUMERAL_WORDS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static NUMERALS: Lazy<Vocabulary> = Lazy::new(Vocabulary::numerals);
+
+    #[test]
+    fn challenge_1() {
+        const INPUT: &[u8; 40] = b"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        assert_eq!(run_1(INPUT), Ok(142));
+    }
+
+    #[test]
+    fn challenge_2() {
+        const INPUT: &[u8; 92] = b"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        assert_eq!(run_2(INPUT), Ok(281));
+    }
+
+    #[test]
+    fn overlapping_words() {
+        assert_eq!(ENGLISH.first_and_last(b"eightwo"), Some((8, 2)));
+        assert_eq!(ENGLISH.first_and_last(b"xoneightx"), Some((1, 8)));
+        assert_eq!(ENGLISH.first_and_last(b"4"), Some((4, 4)));
+        assert_eq!(NUMERALS.first_and_last(b"one"), None);
+    }
+
+    #[test]
+    fn custom_vocabulary() {
+        let french = Vocabulary::new([("zéro", 0), ("un", 1), ("deux", 2), ("trois", 3)]);
+        let document = parse("deux1\ntroisun2".as_bytes()).unwrap();
+        assert_eq!(calibrate(&document, &french), Ok(22 + 31));
+        // Lines with only words from the vocabulary are read, even without English or numerals.
+        let document = parse(b"deuxtrois").unwrap();
+        assert_eq!(calibrate(&document, &french), Ok(23));
+        assert!(part_2(&document).is_err());
+
+        // The longest of the words starting in the same place is read.
+        let ordinals = Vocabulary::new([("sept", 7), ("septième", 1), ("zéro", 0)]);
+        assert_eq!(
+            ordinals.first_and_last("septièmezéro".as_bytes()),
+            Some((1, 0))
+        );
+        assert_eq!(
+            ordinals.first_and_last("zéroseptième".as_bytes()),
+            Some((0, 1))
+        );
+    }
+
+    #[test]
+    fn multiple_digits() {
+        let tens = Vocabulary::new([("seven", 7), ("seventy", 70), ("twelve", 12)]);
+        let document = parse(b"seventyseven\nxtwelve\n3seventy").unwrap();
+        assert_eq!(calibrate(&document, &tens), Ok(77 + 12 + 70));
+        let values = report(&document, &tens)
+            .unwrap()
+            .iter()
+            .map(|report| report.value)
+            .collect::<Vec<_>>();
+        assert_eq!(values, [77, 12, 70]);
+    }
+
+    #[test]
+    fn line_report() {
+        let document = parse(b"eightwothree\nxtwone3\n7").unwrap();
+        let word = |text: &'static str, offset, value| Word {
+            text: text.as_bytes(),
+            offset,
+            value,
+        };
+        let reports = report(&document, &ENGLISH).unwrap();
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| report.words)
+                .collect::<Vec<_>>(),
+            [
+                Some((word("eight", 0, 8), word("three", 7, 3))),
+                Some((word("two", 1, 2), word("3", 6, 3))),
+                Some((word("7", 0, 7), word("7", 0, 7))),
+            ]
+        );
+        assert_eq!(reports[1].line, 2);
+        assert_eq!(reports[1].value, 23);
+
+        let err = report(&document, &NUMERALS).err().unwrap();
+        assert_eq!((err.line, err.text.as_str()), (1, "eightwothree"));
+        let document = parse_with(b"eightwothree\nxtwone3\n7", Policy::Zero).unwrap();
+        let reports = report(&document, &NUMERALS).unwrap();
+        assert_eq!((reports[0].words, reports[0].value), (None, 0));
+        assert_eq!(reports[1].value, 33);
+    }
+
+    #[test]
+    fn line_endings() {
+        let document = parse(b"1abc2\r\npqr3stu8vwx\r\n").unwrap();
+        assert_eq!(document.lines, [&b"1abc2"[..], b"pqr3stu8vwx"]);
+        assert_eq!(part_1(&document), Ok(12 + 38));
+        assert_eq!(run_2(b"two1nine\n"), Ok(29));
+    }
+
+    #[test]
+    fn policies() {
+        const INPUT: &[u8] = b"1abc2\n\nthree\n";
+        let err = run_2(INPUT).err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, ""));
+
+        let document = parse_with(INPUT, Policy::Skip).unwrap();
+        assert_eq!(calibrate(&document, &NUMERALS), Ok(12));
+        assert_eq!(calibrate(&document, &ENGLISH), Ok(12 + 33));
+        assert_eq!(calibrate_numerals(&document), Ok(12));
+        let lines = report(&document, &NUMERALS)
+            .unwrap()
+            .iter()
+            .map(|report| report.line)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, [1]);
+
+        let document = parse_with(INPUT, Policy::Zero).unwrap();
+        assert_eq!(calibrate(&document, &NUMERALS), Ok(12));
+        assert_eq!(calibrate_numerals(&document), Ok(12));
+        let values = report(&document, &NUMERALS)
+            .unwrap()
+            .iter()
+            .map(|report| report.value)
+            .collect::<Vec<_>>();
+        assert_eq!(values, [12, 0, 0]);
+    }
+
+    #[test]
+    fn spelled_out_digits_only() {
+        // Spelled out digits pass parsing, but aren't read in part 1.
+        const INPUT: &[u8] = b"1abc2\nthree";
+        let err = run_1(INPUT).err().unwrap();
+        assert_eq!(
+            (
+                err.line,
+                err.column,
+                err.text.as_str(),
+                err.message.as_str()
+            ),
+            (2, 1, "three", "expected a digit")
+        );
+        let document = parse(INPUT).unwrap();
+        assert_eq!(calibrate(&document, &NUMERALS), Err(err.clone()));
+        assert_eq!(calibrate_numerals(&document), Err(err));
+        assert_eq!(run_2(INPUT), Ok(12 + 33));
+    }
+
+    #[test]
+    fn missing_digit() {
+        let err = run_2(b"1abc2\npqrstu").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "pqrstu"));
+        assert_eq!(run_1(b"1abc2\npqrstu"), Err(err));
+    }
+}
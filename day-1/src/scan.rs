@@ -0,0 +1,114 @@
+//! Fast scanning of calibration documents far bigger than the puzzle input.
+//!
+//! Lines are split with [`memchr`], which searches for newlines with SIMD instructions where the
+//! target has them. ASCII digits are found eight bytes at a time, by testing every byte of a `u64`
+//! at once. The byte-at-a-time [`fallback`] finishes off the bytes left over at the end of a line,
+//! and is kept public to compare against.
+
+/// A `u64` with every byte set to 1.
+const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+/// The low seven bits of every byte.
+const LOW: u64 = ONES * 0x7f;
+/// The high bit of every byte.
+const HIGH: u64 = ONES * 0x80;
+
+/// Splits the input on `\n`, yielding the same lines as `input.split(|&b| b == b'\n')`.
+pub fn lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut start = 0;
+    memchr::memchr_iter(b'\n', input)
+        .chain([input.len()])
+        .map(move |end| {
+            let line = &input[start..end];
+            start = end + 1;
+            line
+        })
+}
+
+/// Finds the offset of the first ASCII digit on the line.
+pub fn first_digit(line: &[u8]) -> Option<usize> {
+    let mut words = line.chunks_exact(8);
+    for (i, word) in words.by_ref().enumerate() {
+        let digits = digits(u64::from_le_bytes(word.try_into().unwrap()));
+        if digits != 0 {
+            return Some(i * 8 + digits.trailing_zeros() as usize / 8);
+        }
+    }
+    let rest = words.remainder();
+    fallback::first_digit(rest).map(|offset| line.len() - rest.len() + offset)
+}
+
+/// Finds the offset of the last ASCII digit on the line.
+pub fn last_digit(line: &[u8]) -> Option<usize> {
+    let mut words = line.rchunks_exact(8);
+    for (i, word) in words.by_ref().enumerate() {
+        let digits = digits(u64::from_le_bytes(word.try_into().unwrap()));
+        if digits != 0 {
+            return Some(line.len() - (i + 1) * 8 + 7 - digits.leading_zeros() as usize / 8);
+        }
+    }
+    // What's left over is at the start of the line.
+    fallback::last_digit(words.remainder())
+}
+
+/// Sets the high bit of each byte of `word` that's an ASCII digit, and clears every other bit.
+///
+/// This is the "has a byte between" trick: each byte's low seven bits are checked against the
+/// bounds separately, so that no carry crosses into the next byte, and bytes with their high bit
+/// set are ruled out by `!word`.
+fn digits(word: u64) -> u64 {
+    let low = word & LOW;
+    // The high bit is set where the byte is below `'9' + 1`, and where it's above `'0' - 1`.
+    let below = ONES * (0x7f + b'9' as u64 + 1) - low;
+    let above = low + ONES * (0x7f - (b'0' as u64 - 1));
+    below & above & !word & HIGH
+}
+
+/// Finding digits one byte at a time.
+pub mod fallback {
+    /// Finds the offset of the first ASCII digit on the line.
+    pub fn first_digit(line: &[u8]) -> Option<usize> {
+        line.iter().position(u8::is_ascii_digit)
+    }
+
+    /// Finds the offset of the last ASCII digit on the line.
+    pub fn last_digit(line: &[u8]) -> Option<usize> {
+        line.iter().rposition(u8::is_ascii_digit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits_in_every_position() {
+        // Non-ASCII bytes whose low seven bits are digits, and the bytes either side of the digits.
+        let others = [b'/', b':', b'a', b'\n', 0xb0, 0xb9, 0xff];
+        for len in 0..20 {
+            for &other in &others {
+                let mut line = vec![other; len];
+                assert_eq!(first_digit(&line), None);
+                assert_eq!(last_digit(&line), None);
+                for i in 0..len {
+                    line[i] = b'0' + (i % 10) as u8;
+                    assert_eq!(first_digit(&line), fallback::first_digit(&line), "{line:?}");
+                    assert_eq!(last_digit(&line), fallback::last_digit(&line), "{line:?}");
+                    line[len - 1 - i] = b'9';
+                    assert_eq!(first_digit(&line), fallback::first_digit(&line), "{line:?}");
+                    assert_eq!(last_digit(&line), fallback::last_digit(&line), "{line:?}");
+                    line.fill(other);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lines() {
+        for input in [&b""[..], b"\n", b"a\nb", b"a\r\nb\n", b"\n\nab\n\n"] {
+            assert_eq!(
+                super::lines(input).collect::<Vec<_>>(),
+                input.split(|&b| b == b'\n').collect::<Vec<_>>()
+            );
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Day 10: Pipe Maze
+//!
+//! Traces the loop of pipes that passes through the starting tile, and counts the tiles enclosed by
+//! it.
+
+use aoc_common::parse::ParseError;
+use aoc_grid::geometry::Direction::{self, Down, Left, Right, Up};
+use aoc_grid::Grid;
+use std::collections::HashSet;
+use tracing::instrument;
+
+/// A sketch of the field of pipes.
+pub struct Sketch {
+    pub tiles: Grid<u8>,
+    /// The `(row, column)` of the starting tile, `S`.
+    pub start: (usize, usize),
+}
+
+/// Parses the puzzle input into a [`Sketch`].
+///
+/// There must be a single starting tile, which connects to exactly two pipes that lead around a
+/// loop back to it.
+#[instrument(skip_all)]
+pub fn parse(input: &[u8]) -> Result<Sketch, ParseError> {
+    let tiles = Grid::try_parse_with(input, |b| match b {
+        b'|' | b'-' | b'L' | b'J' | b'7' | b'F' | b'.' | b'S' => Ok(b),
+        _ => Err("expected a pipe, `.` or `S`"),
+    })?;
+    let start = tiles
+        .position(|tile| *tile == b'S')
+        .ok_or_else(|| ParseError::end_of_input(input, "expected a starting tile, `S`"))?;
+    // The first `S` in the input is the first in the grid too.
+    let offset = input.iter().position(|&b| b == b'S').unwrap();
+    if let Some(other) = input[offset + 1..].iter().position(|&b| b == b'S') {
+        let other = offset + 1 + other;
+        let message = "expected only one starting tile";
+        return Err(ParseError::new(input, &input[other..=other], message));
+    }
+    let start_facing = find_connected_pipes(start, &tiles);
+    if start_facing.len() != 2 || find_loop(&tiles, start, start_facing[0]).is_none() {
+        return Err(ParseError::new(
+            input,
+            &input[offset..=offset],
+            "expected the starting tile to connect to a loop of two pipes",
+        ));
+    }
+    Ok(Sketch { tiles, start })
+}
+
+/// Counts the steps along the loop from the starting tile to the point farthest from it.
+#[instrument(skip_all)]
+pub fn part_1(sketch: &Sketch) -> usize {
+    main_loop(sketch).len() / 2
+}
+
+/// Counts the tiles enclosed by the loop.
+#[instrument(skip_all)]
+pub fn part_2(sketch: &Sketch) -> usize {
+    enclosed(sketch).len()
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &[u8]) -> Result<usize, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &[u8]) -> Result<usize, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+/// Finds the `(row, column)` of every tile on the loop through the starting tile.
+pub fn main_loop(sketch: &Sketch) -> HashSet<(usize, usize)> {
+    let Sketch { tiles, start } = sketch;
+    let start_facing = find_connected_pipes(*start, tiles);
+    find_loop(tiles, *start, start_facing[0]).expect("the loop was checked while parsing")
+}
+
+/// Finds the `(row, column)` of every tile enclosed by the loop, in reading order.
+pub fn enclosed(sketch: &Sketch) -> Vec<(usize, usize)> {
+    let mut tiles = sketch.tiles.clone();
+    let start = sketch.start;
+    let start_facing = find_connected_pipes(start, &tiles);
+    let loop0 =
+        find_loop(&tiles, start, start_facing[0]).expect("the loop was checked while parsing");
+    let start_byte = if start_facing.contains(&Up) && start_facing.contains(&Down) {
+        b'|'
+    } else if start_facing.contains(&Up) && start_facing.contains(&Left) {
+        b'J'
+    } else if start_facing.contains(&Up) && start_facing.contains(&Right) {
+        b'L'
+    } else if start_facing.contains(&Down) && start_facing.contains(&Left) {
+        b'7'
+    } else if start_facing.contains(&Down) && start_facing.contains(&Right) {
+        b'F'
+    } else {
+        b'-'
+    };
+    tiles[start] = start_byte;
+    let mut enclosed = Vec::new();
+    for (row_idx, row) in tiles.rows().enumerate() {
+        let mut inside = false;
+        let mut other_end: Option<u8> = None;
+        for (col_idx, tile) in row.iter().enumerate() {
+            if loop0.contains(&(row_idx, col_idx)) {
+                match *tile {
+                    b'|' => inside = !inside,
+                    b @ b'L' | b @ b'F' => other_end = Some(b),
+                    b'J' => {
+                        if other_end.unwrap() == b'F' {
+                            inside = !inside
+                        }
+                        other_end = None;
+                    }
+                    b'7' => {
+                        if other_end.unwrap() == b'L' {
+                            inside = !inside
+                        }
+                        other_end = None;
+                    }
+                    _ => {}
+                }
+                continue;
+            } else if inside {
+                enclosed.push((row_idx, col_idx));
+            }
+        }
+    }
+    enclosed
+}
+
+/// Follows the pipes from `start`, returning `None` if they lead off the grid or into a tile that
+/// doesn't connect back, instead of around a loop back to `start`.
+#[instrument(skip_all)]
+fn find_loop(
+    tiles: &Grid<u8>,
+    start: (usize, usize),
+    start_facing: Direction,
+) -> Option<HashSet<(usize, usize)>> {
+    let mut position = start;
+    let mut facing = start_facing;
+    let mut loop0 = HashSet::new();
+    loop {
+        position = tiles.step(position, facing)?;
+        loop0.insert(position);
+        facing = match (tiles[position], facing) {
+            (b'S', _) => return Some(loop0),
+            (b'|', Up | Down) | (b'-', Left | Right) => facing,
+            (b'L', Left) | (b'J', Right) => Up,
+            (b'L', Down) | (b'F', Up) => Right,
+            (b'7', Right) | (b'F', Left) => Down,
+            (b'J', Down) | (b'7', Up) => Left,
+            _ => return None,
+        }
+    }
+}
+
+fn find_connected_pipes(pos: (usize, usize), tiles: &Grid<u8>) -> Vec<Direction> {
+    Direction::ALL
+        .into_iter()
+        .filter(|&direction| {
+            let tile = tiles.step(pos, direction).map(|pos| tiles[pos]);
+            matches!(
+                (direction, tile),
+                (Up, Some(b'F' | b'7' | b'|'))
+                    | (Right, Some(b'J' | b'7' | b'-'))
+                    | (Down, Some(b'L' | b'J' | b'|'))
+                    | (Left, Some(b'F' | b'L' | b'-'))
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn challenge_1() {
+        const INPUT_1: &[u8; 29] = b"-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+        const INPUT_2: &[u8; 29] = b"7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+        assert_eq!(run_1(INPUT_1), Ok(4));
+        assert_eq!(run_1(INPUT_2), Ok(8));
+    }
+
+    #[test]
+    fn challenge_2() {
+        const INPUT_1: &[u8; 107] = b"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+        const INPUT_2: &[u8; 98] = b"..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+        const INPUT_3: &[u8; 209] = b".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+        const INPUT_4: &[u8; 209] = b"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+        assert_eq!(run_2(INPUT_1), Ok(4));
+        assert_eq!(run_2(INPUT_2), Ok(4));
+        assert_eq!(run_2(INPUT_3), Ok(8));
+        assert_eq!(run_2(INPUT_4), Ok(10));
+    }
+
+    #[test]
+    fn no_loop() {
+        for input in [
+            &b"S"[..],
+            b"S-7\n|.|\nL-.",
+            b"S-7\n|.|\nL-|",
+            b"-S-",
+            b".|.\n-S-\nF-J",
+        ] {
+            let err = parse(input).err().unwrap();
+            assert_eq!(err.text, "S", "{}", String::from_utf8_lossy(input));
+        }
+        assert_eq!(run_1(b"S7\nLJ"), Ok(2));
+        let err = parse(b"FS\nSJ").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
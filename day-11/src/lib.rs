@@ -0,0 +1,211 @@
+//! Day 11: Cosmic Expansion
+//!
+//! Sums the shortest distances between every pair of galaxies in an image of an expanding universe.
+
+use aoc_common::parse::ParseError;
+use aoc_grid::Grid;
+use std::cmp::{max, min};
+use tracing::instrument;
+
+/// An image of the universe, before expansion.
+pub struct Image {
+    /// The `(row, column)` of each galaxy.
+    pub galaxies: Vec<(usize, usize)>,
+    /// Whether each row contains no galaxies, and so expands.
+    pub expanded_rows: Vec<bool>,
+    /// Whether each column contains no galaxies, and so expands.
+    pub expanded_cols: Vec<bool>,
+}
+
+/// Parses the puzzle input into an [`Image`].
+#[instrument(skip_all)]
+pub fn parse(input: &[u8]) -> Result<Image, ParseError> {
+    let universe = Grid::try_parse_with(input, |b| match b {
+        b'.' | b'#' => Ok(b),
+        _ => Err("expected `.` or `#`"),
+    })?;
+    let expanded_rows = universe
+        .rows()
+        .map(|row| row.iter().all(|b| b == &b'.'))
+        .collect::<Vec<_>>();
+    let expanded_cols = universe
+        .columns()
+        .map(|mut col| col.all(|b| b == &b'.'))
+        .collect::<Vec<_>>();
+    let galaxies = universe
+        .iter()
+        .filter_map(|(pos, point)| if *point == b'#' { Some(pos) } else { None })
+        .collect::<Vec<_>>();
+    Ok(Image {
+        galaxies,
+        expanded_rows,
+        expanded_cols,
+    })
+}
+
+/// Sums the distances between every pair of galaxies, where each empty row and column is twice as
+/// big, or returns `None` if the sum overflows.
+#[instrument(skip_all)]
+pub fn part_1(image: &Image) -> Option<usize> {
+    run(image, 2)
+}
+
+/// How much bigger each empty row and column of the image is than it appears.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// The factor each empty row and column grows by, which must be at least 1.
+    pub expansion: usize,
+}
+
+/// The puzzle's expansion, by a factor of one million.
+impl Default for Params {
+    fn default() -> Self {
+        Self { expansion: 1000000 }
+    }
+}
+
+/// Sums the distances between every pair of galaxies, where each empty row and column is one
+/// million times as big, or returns `None` if the sum overflows.
+#[instrument(skip_all)]
+pub fn part_2(image: &Image) -> Option<usize> {
+    part_2_with(image, &Params::default())
+}
+
+/// Sums the distances between every pair of galaxies, where each empty row and column is
+/// `params.expansion` times as big. Returns `None` if the expansion is 0, or the sum overflows.
+pub fn part_2_with(image: &Image, params: &Params) -> Option<usize> {
+    run(image, params.expansion)
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &[u8]) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &[u8]) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+fn run(image: &Image, factor: usize) -> Option<usize> {
+    let Image {
+        galaxies,
+        expanded_rows,
+        expanded_cols,
+    } = image;
+    let growth = factor.checked_sub(1)?;
+    let mut sum = 0usize;
+    for (galaxy_idx, galaxy) in galaxies.iter().enumerate() {
+        for other in galaxies.iter().skip(galaxy_idx + 1) {
+            let expanded_rows = (min(other.0, galaxy.0)..max(other.0, galaxy.0))
+                .filter(|row_idx| expanded_rows[*row_idx])
+                .count();
+            let expanded_cols = (min(other.1, galaxy.1)..max(other.1, galaxy.1))
+                .filter(|col_idx| expanded_cols[*col_idx])
+                .count();
+            let growth = (expanded_rows + expanded_cols).checked_mul(growth)?;
+            let distance = other.0.abs_diff(galaxy.0) + other.1.abs_diff(galaxy.1);
+            sum = sum.checked_add(distance)?.checked_add(growth)?;
+        }
+    }
+    Some(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const INPUT: &[u8; 109] = b"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(374)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run(&parse(INPUT).unwrap(), 10), Some(1030));
+        assert_eq!(run(&parse(INPUT).unwrap(), 100), Some(8410));
+    }
+
+    #[test]
+    fn expansion() {
+        let image = parse(INPUT).unwrap();
+        assert_eq!(part_2_with(&image, &Params { expansion: 10 }), Some(1030));
+        assert_eq!(
+            part_2_with(&image, &Params { expansion: 2 }),
+            part_1(&image)
+        );
+    }
+
+    #[test]
+    fn unusable_expansion() {
+        let image = parse(INPUT).unwrap();
+        assert_eq!(part_2_with(&image, &Params { expansion: 0 }), None);
+        let expansion = usize::MAX / 2;
+        assert_eq!(part_2_with(&image, &Params { expansion }), None);
+    }
+
+    /// Expands the image by actually repeating its empty rows and columns, and then sums the
+    /// distances between the galaxies' new positions.
+    fn brute_force(image: &[Vec<bool>], factor: usize) -> usize {
+        let expand = |rows: &[Vec<bool>]| -> Vec<Vec<bool>> {
+            rows.iter()
+                .flat_map(|row| {
+                    let copies = if row.contains(&true) { 1 } else { factor };
+                    std::iter::repeat_n(row.clone(), copies)
+                })
+                .collect()
+        };
+        let transpose = |rows: &[Vec<bool>]| -> Vec<Vec<bool>> {
+            (0..rows[0].len())
+                .map(|col| rows.iter().map(|row| row[col]).collect())
+                .collect()
+        };
+        let expanded = transpose(&expand(&transpose(&expand(image))));
+        let mut galaxies = Vec::new();
+        for (row_idx, row) in expanded.iter().enumerate() {
+            for (col_idx, galaxy) in row.iter().enumerate() {
+                if *galaxy {
+                    galaxies.push((row_idx, col_idx));
+                }
+            }
+        }
+        let mut sum = 0;
+        for (idx, a) in galaxies.iter().enumerate() {
+            for b in &galaxies[idx + 1..] {
+                sum += a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+            }
+        }
+        sum
+    }
+
+    proptest! {
+        #[test]
+        fn run_matches_brute_force(
+            image in (1..10usize).prop_flat_map(|width| {
+                let row = prop::collection::vec(prop::bool::weighted(0.2), width);
+                prop::collection::vec(row, 1..10)
+            }),
+            factor in 1..5usize,
+        ) {
+            let input = image
+                .iter()
+                .map(|row| row.iter().map(|&galaxy| if galaxy { '#' } else { '.' }).collect())
+                .collect::<Vec<String>>()
+                .join("\n");
+            let sum = run(&parse(input.as_bytes()).unwrap(), factor);
+            prop_assert_eq!(sum, Some(brute_force(&image, factor)));
+        }
+    }
+}
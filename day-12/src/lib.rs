@@ -0,0 +1,320 @@
+//! Day 12: Hot Springs
+//!
+//! Counts the possible arrangements of operational and damaged springs that agree with each row's
+//! damaged records.
+
+use aoc_common::parse::{self, ParseError};
+use cached::proc_macro::cached;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use tracing::instrument;
+
+/// A single row of the condition records.
+#[derive(Debug)]
+pub struct Row {
+    /// The condition of each spring in the row.
+    pub conditions: Vec<Condition>,
+    /// The size of each contiguous group of damaged springs in the row, in order, which is at least
+    /// 1.
+    pub groups: Vec<u8>,
+}
+
+/// Parses each line of the puzzle input into a [`Row`].
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Row>, ParseError> {
+    input.lines().map(|line| parse_line(input, line)).collect()
+}
+
+/// Sums the number of possible arrangements of each row, or returns `None` if the sum overflows.
+#[instrument(skip_all)]
+pub fn part_1(rows: &[Row]) -> Option<usize> {
+    sum(rows.iter().map(arrangements).collect::<Option<Vec<_>>>()?)
+}
+
+/// Sums the number of possible arrangements of each row, once each row has been unfolded, or returns
+/// `None` if the sum overflows.
+///
+/// The rows are split between threads when the `parallel` feature is enabled.
+#[instrument(skip_all)]
+pub fn part_2(rows: &[Row]) -> Option<usize> {
+    part_2_with(rows, &Params::default())
+}
+
+/// How many copies of itself each row unfolds into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub copies: usize,
+}
+
+/// The puzzle's rows, which unfold into five copies.
+impl Default for Params {
+    fn default() -> Self {
+        Self { copies: 5 }
+    }
+}
+
+/// Sums the number of possible arrangements of each row, once each row has been unfolded into
+/// `params.copies` copies, or returns `None` if the sum overflows.
+pub fn part_2_with(rows: &[Row], params: &Params) -> Option<usize> {
+    #[cfg(feature = "parallel")]
+    let rows = rows.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let rows = rows.iter();
+    let counts = rows
+        .map(|row| unfold(row, params.copies))
+        .map(|row| arrangements(&row))
+        .collect::<Option<Vec<_>>>()?;
+    sum(counts)
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+/// Sums the counts of arrangements, or returns `None` if the sum overflows.
+fn sum(counts: Vec<usize>) -> Option<usize> {
+    counts
+        .into_iter()
+        .try_fold(0usize, |sum, count| sum.checked_add(count))
+}
+
+/// Replaces the row's conditions with copies of themselves separated by unknown springs, and its
+/// groups with as many copies of themselves.
+fn unfold(row: &Row, copies: usize) -> Row {
+    Row {
+        conditions: row
+            .conditions
+            .repeat(copies)
+            .chunks_exact(row.conditions.len())
+            .collect::<Vec<_>>()
+            .join(&Condition::Unknown),
+        groups: row.groups.repeat(copies),
+    }
+}
+
+/// Counts the possible arrangements of damaged springs in the row, or returns `None` if the count
+/// overflows.
+pub fn arrangements(row: &Row) -> Option<usize> {
+    arrangements0(row.conditions.to_vec(), row.groups.to_vec(), None)
+}
+
+// The cache is shared by every row ever counted, so it only keeps the most recent results.
+#[cached(size = 10000)]
+fn arrangements0(
+    conditions: Vec<Condition>,
+    groups: Vec<u8>,
+    current_group_size: Option<u8>,
+) -> Option<usize> {
+    match conditions.first() {
+        None => {
+            if groups.is_empty() || (groups.len() == 1 && current_group_size == Some(groups[0])) {
+                // We've reached the end of the row and all the groups have been filled.
+                Some(1)
+            } else {
+                // We've reached the end of the row, but we have not filled all the groups.
+                Some(0)
+            }
+        }
+        Some(Condition::Operational) => {
+            if let Some(current_group_size) = current_group_size {
+                if groups[0] > current_group_size {
+                    // The current group has not been filled.
+                    Some(0)
+                } else {
+                    // We've reached the end of the current group.
+                    arrangements0(conditions[1..].to_vec(), groups[1..].to_vec(), None)
+                }
+            } else {
+                // Nothing else to check here. Just move on.
+                arrangements0(conditions[1..].to_vec(), groups, None)
+            }
+        }
+        Some(Condition::Damaged) => {
+            if let Some(current_group_size) = current_group_size {
+                if groups[0] == current_group_size {
+                    // The current group is overfilled.
+                    Some(0)
+                } else {
+                    // Carry on moving through the current group.
+                    arrangements0(
+                        conditions[1..].to_vec(),
+                        groups,
+                        Some(current_group_size + 1),
+                    )
+                }
+            } else if groups.is_empty() {
+                // There are no more groups.
+                Some(0)
+            } else {
+                // We've entered a new group.
+                arrangements0(conditions[1..].to_vec(), groups, Some(1))
+            }
+        }
+        Some(Condition::Unknown) => {
+            if let Some(current_group_size) = current_group_size {
+                if groups[0] > current_group_size {
+                    // The current group has not been filled yet, so the current spring has to be damaged.
+                    arrangements0(
+                        conditions[1..].to_vec(),
+                        groups,
+                        Some(current_group_size + 1),
+                    )
+                } else {
+                    // The current group has been filled, so the current spring has to be operational.
+                    arrangements0(conditions[1..].to_vec(), groups[1..].to_vec(), None)
+                }
+            } else {
+                // We are not in a group, so the current spring could be either operational or damaged (if there are remaining groups).
+                let operational = arrangements0(conditions[1..].to_vec(), groups.clone(), None)?;
+                let damaged = if groups.is_empty() {
+                    // There are no more groups, so the spring cannot be damaged.
+                    0
+                } else {
+                    // There are more groups, so the spring could be damaged.
+                    arrangements0(conditions[1..].to_vec(), groups.to_vec(), Some(1))?
+                };
+                operational.checked_add(damaged)
+            }
+        }
+    }
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Row, ParseError> {
+    let (conditions, groups) = parse::split_once(input, line, " ")?;
+    if conditions.is_empty() {
+        return Err(ParseError::new(input, conditions, "expected a condition"));
+    }
+    let conditions = parse::chars(input, conditions, "`.`, `#` or `?`", |c| match c {
+        '.' => Some(Condition::Operational),
+        '#' => Some(Condition::Damaged),
+        '?' => Some(Condition::Unknown),
+        _ => None,
+    })?;
+    let groups = groups
+        .split(',')
+        .map(|group| match parse::number(input, group)? {
+            0 => Err(ParseError::new(
+                input,
+                group,
+                "expected a group of at least 1",
+            )),
+            size => Ok(size),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Row { conditions, groups })
+}
+
+/// The condition of a single spring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Condition {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const INPUT: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(21)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(525152)));
+    }
+
+    #[test]
+    fn copies() {
+        let rows = parse(INPUT).unwrap();
+        assert_eq!(part_2_with(&rows, &Params { copies: 1 }), Some(21));
+    }
+
+    #[test]
+    fn arrangements_test() {
+        let line = "?###???????? 3,2,1";
+        assert_eq!(arrangements(&parse_line(line, line).unwrap()), Some(10));
+    }
+
+    #[test]
+    fn overflow() {
+        let line = format!("{} 1,1,1,1,1,1,1,1,1,1", "?".repeat(100));
+        let rows = parse(&line).unwrap();
+        assert!(part_1(&rows).is_some());
+        assert_eq!(part_2(&rows), None);
+    }
+
+    #[test]
+    fn empty_group() {
+        let err = parse("#.# 1,1\n#.# 0,1").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "0"));
+    }
+
+    /// Tries every combination of conditions for the unknown springs.
+    fn brute_force(row: &Row) -> usize {
+        let unknown = row
+            .conditions
+            .iter()
+            .filter(|condition| **condition == Condition::Unknown)
+            .count();
+        (0..1u32 << unknown)
+            .filter(|combination| {
+                // Each bit of the combination is whether the corresponding unknown spring is damaged.
+                let mut bits = (0..unknown).map(|bit| combination & (1 << bit) != 0);
+                let mut groups = Vec::new();
+                let mut current = 0;
+                for condition in &row.conditions {
+                    let damaged = match condition {
+                        Condition::Operational => false,
+                        Condition::Damaged => true,
+                        Condition::Unknown => bits.next().unwrap(),
+                    };
+                    if damaged {
+                        current += 1;
+                    } else if current > 0 {
+                        groups.push(current);
+                        current = 0;
+                    }
+                }
+                if current > 0 {
+                    groups.push(current);
+                }
+                groups == row.groups
+            })
+            .count()
+    }
+
+    proptest! {
+        #[test]
+        fn arrangements_matches_brute_force(
+            conditions in prop::collection::vec(
+                prop_oneof![
+                    Just(Condition::Operational),
+                    Just(Condition::Damaged),
+                    Just(Condition::Unknown),
+                ],
+                1..14,
+            ),
+            groups in prop::collection::vec(1..5u8, 1..5),
+        ) {
+            let row = Row { conditions, groups };
+            prop_assert_eq!(arrangements(&row), Some(brute_force(&row)));
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Day 13: Point of Incidence
+//!
+//! Finds the line of reflection in each pattern of ash and rocks, before and after fixing the smudge
+//! on each pattern's mirror.
+
+use aoc_common::parse::ParseError;
+use aoc_grid::Grid;
+use tracing::instrument;
+
+/// A single pattern of ash (`.`) and rocks (`#`).
+pub type Pattern = Grid<u8>;
+
+/// Parses the puzzle input into its blank line separated [`Pattern`]s.
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|pattern| {
+            Grid::try_parse_with(pattern.as_bytes(), |b| match b {
+                b'.' | b'#' => Ok(b),
+                _ => Err("expected `.` or `#`"),
+            })
+            .map_err(|err| err.relocate(input, pattern))
+        })
+        .collect()
+}
+
+/// Summarises each pattern's line of reflection: the number of columns left of a vertical line, or
+/// 100 times the number of rows above a horizontal line. Returns `None` if a pattern has no line of
+/// reflection.
+#[instrument(skip_all)]
+pub fn part_1(patterns: &[Pattern]) -> Option<usize> {
+    patterns
+        .iter()
+        .map(|pattern| compute_pattern(pattern, None))
+        .sum()
+}
+
+/// Summarises each pattern's new line of reflection once its smudge has been fixed. Returns `None`
+/// if a pattern has no line of reflection, either before or after fixing any one smudge.
+#[instrument(skip_all)]
+pub fn part_2(patterns: &[Pattern]) -> Option<usize> {
+    patterns.iter().map(fix_smudge).sum()
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+fn fix_smudge(pattern: &Pattern) -> Option<usize> {
+    let mut pattern = pattern.clone();
+    let original = compute_pattern(&pattern, None)?;
+    for pos in pattern.positions() {
+        flip_mirror(&mut pattern, pos);
+        if let Some(num) = compute_pattern(&pattern, Some(original)) {
+            if num != original {
+                return Some(num);
+            }
+        }
+        flip_mirror(&mut pattern, pos);
+    }
+    None
+}
+
+fn compute_pattern(pattern: &Pattern, ignore: Option<usize>) -> Option<usize> {
+    if let Some(num) = find_reflection(
+        pattern,
+        ignore.and_then(|n| if n % 100 == 0 { Some(n / 100) } else { None }),
+    ) {
+        return Some(num * 100);
+    }
+    find_reflection(
+        &pattern.transpose(),
+        ignore.and_then(|n| if n % 100 != 0 { Some(n) } else { None }),
+    )
+}
+
+/// Finds a horizontal line of reflection in `grid`, returning the number of rows above it.
+fn find_reflection(grid: &Pattern, ignore: Option<usize>) -> Option<usize> {
+    'outer: for idx in 1..grid.height() {
+        if Some(idx) == ignore {
+            continue;
+        }
+        if grid[idx - 1] == grid[idx] {
+            for upper_idx in idx + 1..grid.height() {
+                if let Some(lower) = (2 * idx - upper_idx)
+                    .checked_sub(1)
+                    .map(|lower_idx| &grid[lower_idx])
+                {
+                    if *lower != grid[upper_idx] {
+                        continue 'outer;
+                    }
+                } else {
+                    return Some(idx);
+                }
+            }
+            return Some(idx);
+        }
+    }
+    None
+}
+
+fn flip_mirror(pattern: &mut Pattern, pos: (usize, usize)) {
+    let mirror = &mut pattern[pos];
+    match *mirror {
+        b'.' => *mirror = b'#',
+        b'#' => *mirror = b'.',
+        _ => panic!("Unrecognised character"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(405)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(400)));
+    }
+
+    #[test]
+    fn no_reflection() {
+        assert!(parse("").is_err());
+        assert_eq!(run_1("#"), Ok(None));
+        assert_eq!(run_1("#.\n.."), Ok(None));
+        assert_eq!(run_1("#.\n#."), Ok(Some(100)));
+        assert_eq!(run_2("#.\n#."), Ok(None));
+    }
+}
@@ -0,0 +1,195 @@
+//! Day 14: Parabolic Reflector Dish
+//!
+//! Tilts a platform of rounded (`O`) and cube-shaped (`#`) rocks, and measures the load the rounded
+//! rocks place on its north support beams.
+
+use aoc_common::parse::ParseError;
+use aoc_grid::Grid;
+use std::collections::HashMap;
+use tracing::{info_span, instrument};
+
+/// The platform, as a grid of `O`, `#` and `.` bytes.
+pub type Platform = Grid<u8>;
+
+/// Parses the puzzle input into a [`Platform`].
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Platform, ParseError> {
+    Grid::try_parse_with(input.as_bytes(), |b| match b {
+        b'O' | b'#' | b'.' => Ok(b),
+        _ => Err("expected `O`, `#` or `.`"),
+    })
+}
+
+/// Measures the total load after tilting the platform north.
+#[instrument(skip_all)]
+pub fn part_1(platform: &Platform) -> usize {
+    load(&tilt_north(platform))
+}
+
+/// Measures the total load after running one billion spin cycles, each of which tilts the platform
+/// north, west, south and then east.
+#[instrument(skip_all)]
+pub fn part_2(platform: &Platform) -> usize {
+    part_2_with(platform, &Params::default())
+}
+
+/// How many spin cycles to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub cycles: usize,
+}
+
+/// The puzzle's one billion spin cycles.
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            cycles: 1_000_000_000,
+        }
+    }
+}
+
+/// Measures the total load after running `params.cycles` spin cycles.
+pub fn part_2_with(platform: &Platform, params: &Params) -> usize {
+    let mut rocks = platform.clone();
+    let mut mem = HashMap::<Platform, usize>::new();
+    let cycle_detection = info_span!("cycle_detection").entered();
+    for i in 0..params.cycles {
+        mem.insert(rocks.clone(), i);
+        cycle(&mut rocks);
+        if let Some(cycle_start) = mem.get(&rocks) {
+            let idx = cycle_start + (params.cycles - cycle_start) % (i + 1 - cycle_start);
+            rocks = mem.into_iter().find(|(_, value)| *value == idx).unwrap().0;
+            break;
+        }
+    }
+    cycle_detection.exit();
+    load(&rocks)
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<usize, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<usize, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+/// Returns a copy of the platform, tilted north.
+pub fn tilt_north(platform: &Platform) -> Platform {
+    let mut rocks = platform.clone();
+    roll(&mut rocks);
+    rocks
+}
+
+/// Returns a copy of the platform after a single spin cycle.
+pub fn spin_cycle(platform: &Platform) -> Platform {
+    let mut rocks = platform.clone();
+    cycle(&mut rocks);
+    rocks
+}
+
+/// Tilts the platform north, west, south and then east. Rotating the platform clockwise brings its
+/// west edge to the north, so this is done by tilting north and rotating, four times over.
+#[instrument(skip_all)]
+fn cycle(rocks: &mut Platform) {
+    for _ in 0..4 {
+        roll(rocks);
+        *rocks = rocks.rotate_clockwise();
+    }
+}
+
+/// Tilts the platform north, rolling every rounded rock as far north as it will go.
+fn roll(rocks: &mut Platform) {
+    // Rocks are visited from north to south, so every rock north of the current one has already
+    // settled.
+    for (row_idx, col_idx) in rocks.positions() {
+        if rocks[(row_idx, col_idx)] == b'O' {
+            let mut to_idx = row_idx;
+            while to_idx > 0 && rocks[(to_idx - 1, col_idx)] == b'.' {
+                to_idx -= 1;
+            }
+            rocks[(row_idx, col_idx)] = b'.';
+            rocks[(to_idx, col_idx)] = b'O';
+        }
+    }
+}
+
+fn load(rocks: &Platform) -> usize {
+    let mut sum = 0;
+    for (distance, row) in rocks.rows().rev().enumerate() {
+        for rock in row {
+            if *rock == b'O' {
+                sum += distance + 1;
+            }
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(136));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(64));
+    }
+
+    #[test]
+    fn cycles() {
+        let platform = parse(INPUT).unwrap();
+        let once = load(&spin_cycle(&platform));
+        assert_eq!(part_2_with(&platform, &Params { cycles: 1 }), once);
+        assert_eq!(
+            part_2_with(&platform, &Params { cycles: 0 }),
+            load(&platform)
+        );
+        // The load repeats every seven cycles once it's settled into a loop.
+        assert_eq!(
+            part_2_with(
+                &platform,
+                &Params {
+                    cycles: 1_000_000_007
+                }
+            ),
+            64
+        );
+    }
+
+    #[test]
+    fn spin() {
+        let platform = parse(INPUT).unwrap();
+        let after = parse(
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....",
+        )
+        .unwrap();
+        assert_eq!(spin_cycle(&platform), after);
+    }
+}
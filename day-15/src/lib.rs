@@ -0,0 +1,140 @@
+//! Day 15: Lens Library
+//!
+//! Runs the HASH algorithm over the steps of an initialization sequence, and follows the sequence
+//! to arrange lenses into boxes.
+
+use aoc_common::parse::ParseError;
+use tracing::instrument;
+
+/// A single step of the initialization sequence.
+pub struct Step<'a> {
+    /// The full text of the step.
+    pub text: &'a [u8],
+    /// The label of the lens the step operates on.
+    pub label: &'a [u8],
+    pub operation: Operation,
+}
+
+/// The operation a step performs on the box selected by its label.
+pub enum Operation {
+    /// Removes the labelled lens from the box (`-`).
+    Remove,
+    /// Inserts a lens with the given focal length into the box, replacing any existing lens with
+    /// the same label (`=`).
+    Insert(u8),
+}
+
+/// Parses the comma separated puzzle input into its [`Step`]s.
+#[instrument(skip_all)]
+pub fn parse(input: &[u8]) -> Result<Vec<Step<'_>>, ParseError> {
+    input
+        .split(|b| *b == b',')
+        .map(|step| parse_step(input, step))
+        .collect()
+}
+
+/// Sums the hash of every step.
+#[instrument(skip_all)]
+pub fn part_1(steps: &[Step]) -> usize {
+    steps.iter().map(|step| hash(step.text)).sum()
+}
+
+/// Sums the focusing power of every lens once every step has been performed.
+#[instrument(skip_all)]
+pub fn part_2(steps: &[Step]) -> usize {
+    let mut hashmap = vec![Vec::<(&[u8], u8)>::new(); 256];
+    for step in steps {
+        let label = step.label;
+        let hash = hash(label);
+        let lenses = hashmap.get_mut(hash).unwrap();
+        match step.operation {
+            Operation::Insert(focal_length) => {
+                if let Some(lens) = lenses.iter_mut().find(|(other, _)| *other == label) {
+                    lens.1 = focal_length;
+                } else {
+                    lenses.push((label, focal_length));
+                }
+            }
+            Operation::Remove => {
+                if let Some(position) = lenses.iter().position(|(other, _)| *other == label) {
+                    lenses.remove(position);
+                }
+            }
+        }
+    }
+    hashmap
+        .into_iter()
+        .enumerate()
+        .map(|(box_idx, lenses)| {
+            lenses
+                .into_iter()
+                .enumerate()
+                .map(|(lens_idx, (_, focal_length))| {
+                    (1 + box_idx) * (lens_idx + 1) * focal_length as usize
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &[u8]) -> Result<usize, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &[u8]) -> Result<usize, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+fn parse_step<'a>(input: &[u8], step: &'a [u8]) -> Result<Step<'a>, ParseError> {
+    let operation_idx = step
+        .iter()
+        .position(|b| matches!(b, b'=' | b'-'))
+        .ok_or_else(|| ParseError::new(input, step, "expected `=` or `-`"))?;
+    let (label, operation) = step.split_at(operation_idx);
+    let operation = match operation {
+        b"-" => Operation::Remove,
+        [b'=', focal_length @ b'1'..=b'9'] => Operation::Insert(focal_length - b'0'),
+        _ => {
+            let message = "expected `-`, or `=` and a focal length from 1 to 9";
+            return Err(ParseError::new(input, operation, message));
+        }
+    };
+    Ok(Step {
+        text: step,
+        label,
+        operation,
+    })
+}
+
+fn hash(step: &[u8]) -> usize {
+    let mut hash: u8 = 0;
+    for byte in step {
+        hash = hash.wrapping_add(*byte);
+        hash = hash.wrapping_mul(17);
+    }
+    hash as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u8; 51] = b"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(1320));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(145));
+    }
+
+    #[test]
+    fn h() {
+        assert_eq!(hash(b"HASH"), 52);
+    }
+}
@@ -0,0 +1,209 @@
+//! Day 16: The Floor Will Be Lava
+//!
+//! Traces a beam of light through a contraption of mirrors and splitters, counting the tiles it
+//! energizes.
+
+use aoc_common::parse::ParseError;
+use aoc_grid::geometry::Direction::{self, Down, Left, Right, Up};
+use aoc_grid::Grid;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashMap;
+use tracing::instrument;
+
+/// The contraption, as a grid of `.`, `/`, `\`, `|` and `-` bytes.
+pub type Contraption = Grid<u8>;
+
+/// Parses the puzzle input into a [`Contraption`].
+#[instrument(skip_all)]
+pub fn parse(input: &[u8]) -> Result<Contraption, ParseError> {
+    Grid::try_parse_with(input, |b| match b {
+        b'.' | b'/' | b'\\' | b'|' | b'-' => Ok(b),
+        _ => Err("expected `.`, a mirror or a splitter"),
+    })
+}
+
+/// Counts the tiles energized by a beam entering the top-left tile heading right.
+#[instrument(skip_all)]
+pub fn part_1(grid: &Contraption) -> usize {
+    let facing = Right;
+    let pos = (0, 0);
+    run(grid, facing, pos)
+}
+
+/// Counts the tiles energized by a beam entering from whichever edge tile energizes the most.
+///
+/// Each beam is traced on its own thread when the `parallel` feature is enabled.
+#[instrument(skip_all)]
+pub fn part_2(grid: &Contraption) -> usize {
+    let width = grid.width();
+    let height = grid.height();
+    let starts = (0..height)
+        .flat_map(|row_idx| [(Right, (row_idx, 0)), (Left, (row_idx, width - 1))])
+        .chain((0..width).flat_map(|col_idx| [(Down, (0, col_idx)), (Up, (height - 1, col_idx))]))
+        .collect::<Vec<_>>();
+    #[cfg(feature = "parallel")]
+    let starts = starts.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let starts = starts.into_iter();
+    starts
+        .map(|(facing, pos)| run(grid, facing, pos))
+        .max()
+        .unwrap()
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &[u8]) -> Result<usize, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &[u8]) -> Result<usize, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+/// Finds the `(row, column)` of every tile energized by a beam entering `pos` heading `facing`.
+pub fn energized(
+    grid: &Contraption,
+    facing: Direction,
+    pos: (usize, usize),
+) -> Vec<(usize, usize)> {
+    trace(grid, facing, pos).into_keys().collect()
+}
+
+#[instrument(name = "beam", skip_all)]
+fn run(grid: &Contraption, facing: Direction, pos: (usize, usize)) -> usize {
+    trace(grid, facing, pos).len()
+}
+
+/// Traces the beam, returning the directions it has passed through each energized tile in.
+fn trace(
+    grid: &Contraption,
+    facing: Direction,
+    pos: (usize, usize),
+) -> HashMap<(usize, usize), Vec<Direction>> {
+    let mut visited = HashMap::new();
+    step(grid, facing, pos, &mut visited);
+    visited
+}
+
+/// Traces the beam breadth first, returning the tiles it reaches on each step, from entering `pos`
+/// onwards, until every branch of it has left the grid or retraced its path.
+pub fn beam_fronts(
+    grid: &Contraption,
+    facing: Direction,
+    pos: (usize, usize),
+) -> Vec<Vec<(usize, usize)>> {
+    let mut visited = HashMap::<(usize, usize), Vec<Direction>>::new();
+    let mut front = vec![(pos, facing)];
+    let mut fronts = Vec::new();
+    loop {
+        front.retain(|&(pos, facing)| {
+            let directions = visited.entry(pos).or_default();
+            let new = !directions.contains(&facing);
+            if new {
+                directions.push(facing);
+            }
+            new
+        });
+        if front.is_empty() {
+            return fronts;
+        }
+        fronts.push(front.iter().map(|&(pos, _)| pos).collect());
+        front = front
+            .into_iter()
+            .flat_map(|(pos, facing)| {
+                deflect(grid[pos], facing)
+                    .iter()
+                    .filter_map(move |&facing| Some((grid.step(pos, facing)?, facing)))
+            })
+            .collect();
+    }
+}
+
+fn step(
+    grid: &Contraption,
+    facing: Direction,
+    pos: (usize, usize),
+    visited: &mut HashMap<(usize, usize), Vec<Direction>>,
+) {
+    let directions = visited.entry(pos).or_default();
+    if directions.contains(&facing) {
+        return;
+    }
+    directions.push(facing);
+    for &facing in deflect(grid[pos], facing) {
+        if let Some(pos) = grid.step(pos, facing) {
+            step(grid, facing, pos, visited);
+        }
+    }
+}
+
+/// Finds the directions a beam heading `facing` leaves a tile in.
+fn deflect(tile: u8, facing: Direction) -> &'static [Direction] {
+    match tile {
+        b'/' => match facing {
+            Up => &[Right],
+            Down => &[Left],
+            Left => &[Down],
+            Right => &[Up],
+        },
+        b'\\' => match facing {
+            Up => &[Left],
+            Down => &[Right],
+            Left => &[Up],
+            Right => &[Down],
+        },
+        b'|' if !facing.is_vertical() => &[Up, Down],
+        b'-' if facing.is_vertical() => &[Left, Right],
+        // Must be b'.', or a splitter hit end-on.
+        _ => match facing {
+            Up => &[Up],
+            Down => &[Down],
+            Left => &[Left],
+            Right => &[Right],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const INPUT: &[u8; 109] = br#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#;
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(46));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(51));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(parse(b"").is_err());
+        assert_eq!(run_2(b"."), Ok(1));
+    }
+
+    #[test]
+    fn fronts() {
+        let grid = parse(INPUT).unwrap();
+        let fronts = beam_fronts(&grid, Right, (0, 0));
+        assert_eq!(fronts[..3], [vec![(0, 0)], vec![(0, 1)], vec![(1, 1)]]);
+        let tiles = fronts.into_iter().flatten().collect::<HashSet<_>>();
+        assert_eq!(tiles.len(), 46);
+    }
+}
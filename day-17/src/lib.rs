@@ -0,0 +1,349 @@
+//! Day 17: Clumsy Crucible
+//!
+//! Finds the path from the top-left to the bottom-right city block that incurs the least heat loss,
+//! given limits on how far a crucible can move before it must turn.
+
+use aoc_common::parse::ParseError;
+use aoc_grid::geometry::Direction;
+use aoc_grid::Grid;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use tracing::instrument;
+
+/// The map of city blocks, as a grid of the heat lost by entering each block.
+pub type Map = Grid<u8>;
+
+/// Parses the puzzle input into a [`Map`].
+#[instrument(skip_all)]
+pub fn parse(input: &[u8]) -> Result<Map, ParseError> {
+    Grid::try_parse_with(input, |b| match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        _ => Err("expected a digit"),
+    })
+}
+
+/// Finds the least heat loss for a crucible that moves at most three blocks before turning, or
+/// `None` if it can't reach the bottom-right block.
+#[instrument(skip_all)]
+pub fn part_1(map: &Map) -> Option<usize> {
+    part_1_with(map, &Params::default())
+}
+
+/// Finds the least heat loss for an ultra crucible, which moves at least four and at most ten
+/// blocks before turning, or `None` if it can't reach the bottom-right block.
+#[instrument(skip_all)]
+pub fn part_2(map: &Map) -> Option<usize> {
+    part_2_with(map, &Params::default())
+}
+
+/// How far each kind of crucible moves in a straight line before it turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// The limits of the crucible in part 1.
+    pub crucible: Limits,
+    /// The limits of the ultra crucible in part 2.
+    pub ultra_crucible: Limits,
+}
+
+/// The fewest and most blocks a crucible moves in a straight line before it turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub min: usize,
+    pub max: usize,
+}
+
+/// The puzzle's crucibles: the crucible moves between one and three blocks before turning, and the
+/// ultra crucible between four and ten.
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            crucible: Limits { min: 1, max: 3 },
+            ultra_crucible: Limits { min: 4, max: 10 },
+        }
+    }
+}
+
+/// Finds the least heat loss for a crucible limited by `params.crucible`.
+pub fn part_1_with(map: &Map, params: &Params) -> Option<usize> {
+    run(map, params.crucible).map(|(heat_loss, _)| heat_loss)
+}
+
+/// Finds the least heat loss for an ultra crucible limited by `params.ultra_crucible`.
+pub fn part_2_with(map: &Map, params: &Params) -> Option<usize> {
+    run(map, params.ultra_crucible).map(|(heat_loss, _)| heat_loss)
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &[u8]) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &[u8]) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+/// Finds the `(row, column)` of every block on the path of least heat loss for a crucible limited
+/// by `params.crucible`, from the top-left to the bottom-right.
+pub fn path_1(map: &Map, params: &Params) -> Option<Vec<(usize, usize)>> {
+    run(map, params.crucible).map(|(_, path)| path)
+}
+
+/// Finds the `(row, column)` of every block on the path of least heat loss for an ultra crucible
+/// limited by `params.ultra_crucible`.
+pub fn path_2(map: &Map, params: &Params) -> Option<Vec<(usize, usize)>> {
+    run(map, params.ultra_crucible).map(|(_, path)| path)
+}
+
+/// A block, along with the direction the crucible moved in to enter it.
+type Key = ((usize, usize), Option<Direction>);
+
+/// Finds the least heat loss, along with the path that incurs it, or `None` if the limits keep the
+/// crucible from ever reaching the finish.
+#[instrument(name = "search", skip_all)]
+fn run(grid: &Map, Limits { min, max }: Limits) -> Option<(usize, Vec<(usize, usize)>)> {
+    let start = (0, 0);
+    let finish = (grid.height() - 1, grid.width() - 1);
+    let start_state = State::new(start, finish);
+
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse(start_state));
+
+    // The block each visited block was turned out of, or `None` for the start.
+    let mut came_from = HashMap::<Key, Option<Key>>::new();
+
+    while let Some(Reverse(state)) = queue.pop() {
+        let key = (state.pos, state.prev_move);
+
+        if state.pos == finish {
+            came_from.insert(key, state.parent);
+            return Some((state.heat_loss, path(grid, &came_from, key)));
+        }
+
+        if came_from.contains_key(&key) {
+            continue;
+        }
+        came_from.insert(key, state.parent);
+
+        // The crucible must turn, so it can neither carry on in nor reverse its last direction.
+        let directions = Direction::ALL.into_iter().filter(|direction| {
+            state
+                .prev_move
+                .is_none_or(|prev| *direction != prev && *direction != prev.reverse())
+        });
+
+        for direction in directions {
+            let mut prev_state = state.clone();
+            prev_state.parent = Some(key);
+
+            for i in 1..=max {
+                if let Some(new_state) = prev_state.try_move(direction, grid, finish) {
+                    if i >= min {
+                        queue.push(Reverse(new_state.clone()));
+                    }
+                    prev_state = new_state.clone();
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Walks back from `end` to the start, filling in the blocks passed along each straight run.
+fn path(grid: &Map, came_from: &HashMap<Key, Option<Key>>, end: Key) -> Vec<(usize, usize)> {
+    let mut path = vec![end.0];
+    let mut key = end;
+    while let Some(parent) = came_from[&key] {
+        // The crucible moved straight from its parent, so retrace its last move until reaching it.
+        let back = key
+            .1
+            .expect("only the start has no previous move")
+            .reverse();
+        let mut pos = key.0;
+        while pos != parent.0 {
+            pos = grid.step(pos, back).unwrap();
+            path.push(pos);
+        }
+        key = parent;
+    }
+    path.reverse();
+    path
+}
+
+#[derive(Clone)]
+struct State {
+    heat_loss: usize,
+    distance_from_finish: usize,
+    pos: (usize, usize),
+    prev_move: Option<Direction>,
+    /// The block the crucible last turned in, from which it moved straight to this one.
+    parent: Option<Key>,
+}
+
+impl State {
+    fn new(pos: (usize, usize), finish: (usize, usize)) -> Self {
+        let mut s = Self {
+            heat_loss: 0,
+            distance_from_finish: 0,
+            pos,
+            prev_move: None,
+            parent: None,
+        };
+        s.set_distance_from(finish);
+        s
+    }
+
+    fn try_move(&self, direction: Direction, grid: &Map, finish: (usize, usize)) -> Option<State> {
+        let mut new_state = self.clone();
+        new_state.pos = grid.step(new_state.pos, direction)?;
+        new_state.prev_move = Some(direction);
+        new_state.add_heat_loss(grid);
+        new_state.set_distance_from(finish);
+        Some(new_state)
+    }
+
+    fn add_heat_loss(&mut self, grid: &Map) {
+        self.heat_loss += grid[self.pos] as usize;
+    }
+
+    fn set_distance_from(&mut self, from: (usize, usize)) {
+        self.distance_from_finish = self.pos.0.abs_diff(from.0) + self.pos.1.abs_diff(from.1);
+    }
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.heat_loss + self.distance_from_finish)
+            .cmp(&(other.heat_loss + other.distance_from_finish))
+            .then_with(|| self.pos.cmp(&other.pos))
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for State {}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.heat_loss + self.distance_from_finish == other.heat_loss + other.distance_from_finish
+            && self.pos == other.pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u8; 181] = br#"2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533"#;
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(102)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(94)));
+    }
+
+    #[test]
+    fn limits() {
+        let map = parse(INPUT).unwrap();
+        let params = Params {
+            crucible: Limits { min: 4, max: 10 },
+            ultra_crucible: Limits { min: 1, max: 3 },
+        };
+        assert_eq!(part_1_with(&map, &params), Some(94));
+        assert_eq!(part_2_with(&map, &params), Some(102));
+    }
+
+    #[test]
+    fn unreachable() {
+        let map = parse(INPUT).unwrap();
+        let params = Params {
+            crucible: Limits { min: 200, max: 300 },
+            ..Params::default()
+        };
+        assert_eq!(part_1_with(&map, &params), None);
+        assert_eq!(path_1(&map, &params), None);
+        // The ultra crucible can't move four blocks in a grid that's three wide.
+        assert_eq!(run_2(b"123\n456\n789"), Ok(None));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(parse(b"").is_err());
+        assert_eq!(run_1(b"1"), Ok(Some(0)));
+    }
+
+    #[test]
+    fn path() {
+        let map = parse(INPUT).unwrap();
+        let params = Params::default();
+        for (path, least) in [(path_1(&map, &params), 102), (path_2(&map, &params), 94)] {
+            let path = path.unwrap();
+            assert_eq!(path.first(), Some(&(0, 0)));
+            assert_eq!(path.last(), Some(&(12, 12)));
+            assert!(path
+                .windows(2)
+                .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1));
+            let heat_loss = path[1..]
+                .iter()
+                .map(|&pos| map[pos] as usize)
+                .sum::<usize>();
+            assert_eq!(heat_loss, least);
+        }
+    }
+
+    #[test]
+    fn cmp() {
+        let a = State {
+            heat_loss: 15,
+            distance_from_finish: 20,
+            pos: (1, 3),
+            prev_move: None,
+            parent: None,
+        };
+        let b = State {
+            heat_loss: 11,
+            distance_from_finish: 20,
+            pos: (1, 3),
+            prev_move: None,
+            parent: None,
+        };
+        assert_eq!(b.cmp(&a), Ordering::Less);
+        let a = State {
+            heat_loss: 10,
+            distance_from_finish: 22,
+            pos: (1, 3),
+            prev_move: None,
+            parent: None,
+        };
+        let b = State {
+            heat_loss: 11,
+            distance_from_finish: 20,
+            pos: (1, 3),
+            prev_move: None,
+            parent: None,
+        };
+        assert_eq!(b.cmp(&a), Ordering::Less);
+    }
+}
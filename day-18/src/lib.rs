@@ -0,0 +1,375 @@
+//! Day 18: Lavaduct Lagoon
+//!
+//! Measures the volume of lava a lagoon can hold once it has been dug out by following the dig plan.
+
+use aoc_common::parse::{self, ParseError};
+use aoc_grid::geometry::{Direction, Point};
+use tracing::instrument;
+
+/// A single step of the dig plan.
+#[derive(Debug)]
+pub struct DigStep<'a> {
+    /// The direction to dig in.
+    pub direction: Direction,
+    /// The number of metres to dig, which is never negative.
+    pub distance: i64,
+    /// The hexadecimal colour code, without the leading `#`.
+    pub colour: &'a str,
+}
+
+/// Parses each line of the puzzle input into a [`DigStep`]. Following the steps, either by their
+/// directions and distances or by their colours, must lead back to the start, without any corner
+/// being too far away to represent.
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<DigStep<'_>>, ParseError> {
+    let plan = input
+        .lines()
+        .map(|line| parse_line(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+    for (instruction, steps) in [
+        (instruction_1 as fn(&DigStep) -> Instruction, "steps"),
+        (instruction_2, "colours"),
+    ] {
+        let Some((points, _)) = find_points(plan.iter().map(instruction)) else {
+            let message = format!("expected the {steps} to stay within reach of the start");
+            return Err(ParseError::end_of_input(input, message));
+        };
+        if points.last().is_some_and(|&end| end != Point::ORIGIN) {
+            let message = format!("expected the {steps} to lead back to the start");
+            return Err(ParseError::end_of_input(input, message));
+        }
+    }
+    Ok(plan)
+}
+
+/// Measures the lagoon dug by following each step's direction and distance, or returns `None` if
+/// the area overflows.
+#[instrument(skip_all)]
+pub fn part_1(plan: &[DigStep]) -> Option<usize> {
+    run(plan, instruction_1)
+}
+
+/// Measures the lagoon dug by following the direction and distance encoded in each step's colour,
+/// or returns `None` if the area overflows.
+#[instrument(skip_all)]
+pub fn part_2(plan: &[DigStep]) -> Option<usize> {
+    run(plan, instruction_2)
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+/// Finds the corners of the lagoon's outline when following each step's direction and distance.
+///
+/// # Panics
+///
+/// If a corner is too far away to represent, which [`parse`] rules out.
+pub fn corners_1(plan: &[DigStep]) -> Vec<Point> {
+    corners(plan, instruction_1)
+}
+
+/// Finds the corners of the lagoon's outline when following the instructions encoded in the colours.
+///
+/// # Panics
+///
+/// If a corner is too far away to represent, which [`parse`] rules out.
+pub fn corners_2(plan: &[DigStep]) -> Vec<Point> {
+    corners(plan, instruction_2)
+}
+
+fn corners(plan: &[DigStep], instruction: fn(&DigStep) -> Instruction) -> Vec<Point> {
+    find_points(plan.iter().map(instruction))
+        .expect("corners should be within reach of the start")
+        .0
+}
+
+fn run(plan: &[DigStep], instruction: fn(&DigStep) -> Instruction) -> Option<usize> {
+    let (points, perimeter) = find_points(plan.iter().map(instruction))?;
+    let area = shoelace_formula(&points)?;
+    picks_theorem(area, perimeter)
+}
+
+/// Finds the corners reached by following the instructions, and the length of the outline between
+/// them, or returns `None` if either overflows.
+fn find_points(instructions: impl Iterator<Item = Instruction>) -> Option<(Vec<Point>, usize)> {
+    let mut points = Vec::new();
+    let mut pos = Point::ORIGIN;
+    let mut perimeter = 0usize;
+    for (direction, distance) in instructions {
+        pos = pos.checked_add(direction.vector() * distance)?;
+        points.push(pos);
+        perimeter = perimeter.checked_add(usize::try_from(distance).ok()?)?;
+    }
+    Some((points, perimeter))
+}
+
+/// Finds the area of the polygon with the given corners, listed either clockwise or
+/// anticlockwise, or returns `None` if it overflows.
+fn shoelace_formula(points: &[Point]) -> Option<usize> {
+    let sum = (0..points.len()).try_fold(0i64, |sum, i| {
+        let p0 = points[i];
+        let p1 = points[(i + 1) % points.len()];
+        let term = (p0.y.checked_add(p1.y)?).checked_mul(p0.x.checked_sub(p1.x)?)?;
+        sum.checked_add(term)
+    })?;
+    usize::try_from(sum.unsigned_abs() / 2).ok()
+}
+
+fn picks_theorem(area: usize, perimeter: usize) -> Option<usize> {
+    area.checked_add(perimeter / 2)?.checked_add(1)
+}
+
+fn parse_line<'a>(input: &str, line: &'a str) -> Result<DigStep<'a>, ParseError> {
+    let (direction, rest) = parse::split_once(input, line, " ")?;
+    let (distance, colour) = parse::split_once(input, rest, " ")?;
+    let direction = match direction {
+        "R" => Direction::Right,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "U" => Direction::Up,
+        _ => {
+            let message = "expected `R`, `D`, `L` or `U`";
+            return Err(ParseError::new(input, direction, message));
+        }
+    };
+    let colour = parse::strip_prefix(input, colour, "(#")?;
+    let colour = parse::strip_suffix(input, colour, ")")?;
+    // The colour encodes part 2's instruction, so check now that it can be decoded.
+    if colour.len() != 6 || !colour.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseError::new(
+            input,
+            colour,
+            "expected six hexadecimal digits",
+        ));
+    }
+    if !matches!(&colour[5..], "0" | "1" | "2" | "3") {
+        let message = "expected a direction from 0 to 3";
+        return Err(ParseError::new(input, &colour[5..], message));
+    }
+    Ok(DigStep {
+        direction,
+        // Bounded, so that a single step can't overflow.
+        distance: parse::number::<u32>(input, distance)?.into(),
+        colour,
+    })
+}
+
+fn instruction_1(step: &DigStep) -> Instruction {
+    (step.direction, step.distance)
+}
+
+/// The colour's first five hexadecimal digits are the distance, and its last is the direction: 0
+/// is right, 1 is down, 2 is left and 3 is up.
+fn instruction_2(step: &DigStep) -> Instruction {
+    let (distance, direction) = step.colour.split_at(step.colour.len() - 1);
+    let direction = match direction {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        _ => Direction::Up,
+    };
+    (direction, i64::from_str_radix(distance, 16).unwrap())
+}
+
+type Instruction = (Direction, i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    const INPUT: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(62)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(952408144115)));
+    }
+
+    #[test]
+    fn anticlockwise() {
+        // The example's steps, walked backwards.
+        let reversed = INPUT
+            .lines()
+            .rev()
+            .map(|line| {
+                let direction = match &line[..1] {
+                    "R" => "L",
+                    "D" => "U",
+                    "L" => "R",
+                    _ => "D",
+                };
+                format!(
+                    "{direction}{} (#000000)",
+                    &line[1..line.find(" (").unwrap()]
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(run_1(&reversed), Ok(Some(62)));
+    }
+
+    #[test]
+    fn open_loop() {
+        let err = parse("R 2 (#000020)\nD 2 (#000021)\nL 2 (#000022)")
+            .err()
+            .unwrap();
+        assert_eq!(err.message, "expected the steps to lead back to the start");
+        let err = parse("R 2 (#000020)\nL 2 (#000021)").err().unwrap();
+        assert_eq!(
+            err.message,
+            "expected the colours to lead back to the start"
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let err = parse("R -2 (#000020)\nL -2 (#000022)").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 3, "-2"));
+        let err = parse("R 9223372036854775807 (#000020)").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+
+    #[test]
+    fn overflow() {
+        let side = 1 << 40;
+        let plan = [
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+            Direction::Up,
+        ]
+        .map(|direction| DigStep {
+            direction,
+            distance: side,
+            colour: "000000",
+        });
+        assert_eq!(part_1(&plan), None);
+    }
+
+    /// Digs out every cube on the plan's loop, and then every cube that can't be reached from
+    /// outside the loop without crossing it.
+    fn brute_force(plan: &[DigStep]) -> usize {
+        let mut trench = HashSet::from([(0, 0)]);
+        let mut pos = (0i64, 0i64);
+        for step in plan {
+            let (d_row, d_col) = step.direction.delta();
+            for _ in 0..step.distance {
+                pos = (pos.0 + d_row as i64, pos.1 + d_col as i64);
+                trench.insert(pos);
+            }
+        }
+        // Flood fill the cubes outside the loop, within a border around it.
+        let rows = trench.iter().map(|pos| pos.0);
+        let cols = trench.iter().map(|pos| pos.1);
+        let (min_row, max_row) = (rows.clone().min().unwrap() - 1, rows.max().unwrap() + 1);
+        let (min_col, max_col) = (cols.clone().min().unwrap() - 1, cols.max().unwrap() + 1);
+        let mut outside = HashSet::from([(min_row, min_col)]);
+        let mut queue = vec![(min_row, min_col)];
+        while let Some((row, col)) = queue.pop() {
+            for next in [
+                (row - 1, col),
+                (row + 1, col),
+                (row, col - 1),
+                (row, col + 1),
+            ] {
+                let in_bounds =
+                    (min_row..=max_row).contains(&next.0) && (min_col..=max_col).contains(&next.1);
+                if in_bounds && !trench.contains(&next) && outside.insert(next) {
+                    queue.push(next);
+                }
+            }
+        }
+        let area = (max_row - min_row + 1) * (max_col - min_col + 1);
+        area as usize - outside.len()
+    }
+
+    /// Generates the plan for a loop around a random lagoon that's one contiguous range of rows
+    /// tall in each column, such that each column's range overlaps the next. The loop runs either
+    /// clockwise or anticlockwise.
+    fn plan() -> impl Strategy<Value = Vec<DigStep<'static>>> {
+        let columns = prop::collection::vec((1..4i64, 0..6i64, 1..6i64), 1..6);
+        (columns, any::<bool>()).prop_map(|(columns, anticlockwise)| {
+            let mut ranges: Vec<(i64, i64, i64)> = Vec::new();
+            for (width, top, height) in columns {
+                let (mut top, mut bottom) = (top, top + height);
+                if let Some(&(_, previous_top, previous_bottom)) = ranges.last() {
+                    top = top.min(previous_bottom - 1);
+                    bottom = bottom.max(previous_top + 1).max(top + 1);
+                }
+                ranges.push((width, top, bottom));
+            }
+            let mut plan = Vec::new();
+            let mut dig = |direction: Direction, distance: i64| {
+                if distance != 0 {
+                    plan.push(DigStep {
+                        direction,
+                        distance,
+                        colour: "000000",
+                    });
+                }
+            };
+            let vertical = |distance: i64| {
+                if distance < 0 {
+                    Direction::Up
+                } else {
+                    Direction::Down
+                }
+            };
+            // Along the top of each column from left to right, and then back along the bottom.
+            let (_, first_top, first_bottom) = ranges[0];
+            dig(Direction::Up, first_bottom - first_top);
+            for (idx, &(width, top, _)) in ranges.iter().enumerate() {
+                dig(Direction::Right, width);
+                let next_top = ranges.get(idx + 1).map_or(ranges[idx].2, |next| next.1);
+                dig(vertical(next_top - top), (next_top - top).abs());
+            }
+            for (idx, &(width, _, bottom)) in ranges.iter().enumerate().rev() {
+                dig(Direction::Left, width);
+                if idx > 0 {
+                    let next_bottom = ranges[idx - 1].2;
+                    dig(vertical(next_bottom - bottom), (next_bottom - bottom).abs());
+                }
+            }
+            if anticlockwise {
+                plan.reverse();
+                for step in &mut plan {
+                    step.direction = step.direction.reverse();
+                }
+            }
+            plan
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn run_matches_brute_force(plan in plan()) {
+            prop_assert_eq!(run(&plan, instruction_1), Some(brute_force(&plan)));
+        }
+    }
+}
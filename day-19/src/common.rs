@@ -1,76 +1,215 @@
+use aoc_common::parse::{self, ParseError};
 use std::cmp::Ordering;
 use std::cmp::Ordering::{Greater, Less};
+use std::collections::{HashMap, HashSet};
+use tracing::instrument;
 use Instruction::{Accept, Jump, Reject};
 
+/// The system of workflows, and the parts to be sorted by it.
+pub struct System<'a> {
+    pub workflows: HashMap<&'a str, Workflow<'a>>,
+    pub parts: Vec<Part>,
+}
+
+/// A workflow: a list of rules, checked in order, and the instruction to follow if none match.
 pub struct Workflow<'a> {
-    pub(crate) rules: Vec<Rule<'a>>,
-    pub(crate) final_instruction: Instruction<'a>,
+    pub rules: Vec<Rule<'a>>,
+    pub final_instruction: Instruction<'a>,
 }
 
+/// A rule within a workflow, which applies its instruction if a part meets its condition.
 pub struct Rule<'a> {
-    pub(crate) condition: Condition,
-    pub(crate) instruction: Instruction<'a>,
+    pub condition: Condition,
+    pub instruction: Instruction<'a>,
 }
 
+/// A condition on one of a part's ratings.
 pub struct Condition {
-    pub(crate) category: usize,
-    pub(crate) ordering: Ordering,
-    pub(crate) cmp: usize,
+    /// The index of the rating: 0 is `x`, 1 is `m`, 2 is `a` and 3 is `s`.
+    pub category: usize,
+    /// Whether the rating must be greater than or less than `cmp`.
+    pub ordering: Ordering,
+    pub cmp: usize,
 }
 
+/// What to do with a part.
 pub enum Instruction<'a> {
     Accept,
     Reject,
+    /// Send the part to the named workflow.
     Jump(&'a str),
 }
 
-pub fn parse_workflow(line: &str) -> (&str, Workflow) {
-    let (key, rest) = line.split_once('{').unwrap();
-    let rules = &rest[..rest.len() - 1];
+/// A part's `x`, `m`, `a` and `s` ratings.
+pub type Part = [usize; 4];
+
+/// Parses the puzzle input into a [`System`].
+///
+/// Parts must be accepted or rejected eventually, so the workflows may not send them round in
+/// circles, and every rating must be from 1 to 4000.
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<System<'_>, ParseError> {
+    let (workflows, parts) = parse::split_once(input, input, "\n\n")?;
+
+    let workflows = workflows
+        .lines()
+        .map(|line| parse_workflow(input, line))
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    let parts = parts
+        .lines()
+        .map(|line| parse_part(input, line))
+        .collect::<Result<_, _>>()?;
+
+    if !workflows.contains_key("in") {
+        return Err(ParseError::new(
+            input,
+            &input[..0],
+            "expected an `in` workflow",
+        ));
+    }
+    let instructions = workflows.values().flat_map(|workflow| {
+        (workflow.rules.iter().map(|rule| &rule.instruction)).chain([&workflow.final_instruction])
+    });
+    for instruction in instructions {
+        if let Jump(key) = instruction {
+            if !workflows.contains_key(key) {
+                return Err(ParseError::new(input, *key, "unknown workflow"));
+            }
+        }
+    }
+    let mut visiting = HashSet::new();
+    if let Some(key) = find_loop(&workflows, "in", &mut visiting, &mut HashSet::new()) {
+        let message = "expected a workflow that doesn't lead back to itself";
+        return Err(ParseError::new(input, key, message));
+    }
+
+    Ok(System { workflows, parts })
+}
+
+/// Finds a jump, among the workflows reachable from `key`, back to a workflow that led to it.
+fn find_loop<'a>(
+    workflows: &HashMap<&'a str, Workflow<'a>>,
+    key: &'a str,
+    visiting: &mut HashSet<&'a str>,
+    visited: &mut HashSet<&'a str>,
+) -> Option<&'a str> {
+    if visited.contains(key) {
+        return None;
+    }
+    visiting.insert(key);
+    let workflow = &workflows[key];
+    let instructions =
+        (workflow.rules.iter().map(|rule| &rule.instruction)).chain([&workflow.final_instruction]);
+    for instruction in instructions {
+        if let Jump(next) = *instruction {
+            if visiting.contains(next) {
+                return Some(next);
+            }
+            if let Some(next) = find_loop(workflows, next, visiting, visited) {
+                return Some(next);
+            }
+        }
+    }
+    visiting.remove(key);
+    visited.insert(key);
+    None
+}
+
+fn parse_workflow<'a>(input: &str, line: &'a str) -> Result<(&'a str, Workflow<'a>), ParseError> {
+    let (key, rest) = parse::split_once(input, line, "{")?;
+    let rules = parse::strip_suffix(input, rest, "}")?;
     let mut rules = rules.split(',');
-    let final_instruction = rules.next_back().map(parse_instruction).unwrap();
-    let rules = rules.map(parse_rule).collect();
+    let final_instruction = parse_instruction(rules.next_back().unwrap());
+    let rules = rules
+        .map(|rule| parse_rule(input, rule))
+        .collect::<Result<_, _>>()?;
     let workflow = Workflow {
         rules,
         final_instruction,
     };
-    (key, workflow)
+    Ok((key, workflow))
 }
 
-fn parse_rule(rule: &str) -> Rule {
-    let (condition, instruction) = rule.split_once(':').unwrap();
-    let condition = parse_condition(condition);
+fn parse_rule<'a>(input: &str, rule: &'a str) -> Result<Rule<'a>, ParseError> {
+    let (condition, instruction) = parse::split_once(input, rule, ":")?;
+    let condition = parse_condition(input, condition)?;
     let instruction = parse_instruction(instruction);
-    Rule {
+    Ok(Rule {
         condition,
         instruction,
-    }
+    })
 }
 
-fn parse_condition(condition: &str) -> Condition {
-    let bytes = condition.as_bytes();
-    let category = match bytes[0] {
-        b'x' => 0,
-        b'm' => 1,
-        b'a' => 2,
-        _ => 3,
-    };
-    let ordering = match bytes[1] {
-        b'>' => Greater,
-        _ => Less,
+fn parse_condition(input: &str, condition: &str) -> Result<Condition, ParseError> {
+    let category = parse_category(input, condition)?;
+    let rest = &condition[1..];
+    let ordering = match rest.as_bytes().first() {
+        Some(b'>') => Greater,
+        Some(b'<') => Less,
+        _ => {
+            let end = rest
+                .char_indices()
+                .nth(1)
+                .map_or(rest.len(), |(idx, _)| idx);
+            return Err(ParseError::new(input, &rest[..end], "expected `<` or `>`"));
+        }
     };
-    let cmp = condition.split_at(2).1.parse().unwrap();
-    Condition {
+    let cmp = parse::number(input, &rest[1..])?;
+    Ok(Condition {
         category,
         ordering,
         cmp,
-    }
+    })
 }
 
-fn parse_instruction(instruction: &str) -> Instruction {
+/// Parses the category at the start of `s`.
+fn parse_category(input: &str, s: &str) -> Result<usize, ParseError> {
+    Ok(match s.as_bytes().first() {
+        Some(b'x') => 0,
+        Some(b'm') => 1,
+        Some(b'a') => 2,
+        Some(b's') => 3,
+        _ => {
+            let end = s.char_indices().nth(1).map_or(s.len(), |(idx, _)| idx);
+            let message = "expected `x`, `m`, `a` or `s`";
+            return Err(ParseError::new(input, &s[..end], message));
+        }
+    })
+}
+
+fn parse_instruction(instruction: &str) -> Instruction<'_> {
     match instruction {
         "A" => Accept,
         "R" => Reject,
         _ => Jump(instruction),
     }
 }
+
+fn parse_part(input: &str, part: &str) -> Result<Part, ParseError> {
+    let ratings = parse::strip_prefix(input, part, "{")?;
+    let ratings = parse::strip_suffix(input, ratings, "}")?;
+    let mut categories = ratings.split(',');
+    let mut part = [0; 4];
+    for (rating, prefix) in part.iter_mut().zip(["x=", "m=", "a=", "s="]) {
+        let category = categories
+            .next()
+            .ok_or_else(|| ParseError::new(input, &ratings[ratings.len()..], "expected `,`"))?;
+        let number = parse::strip_prefix(input, category, prefix)?;
+        *rating = parse::number(input, number)?;
+        if !(1..=4000).contains(rating) {
+            return Err(ParseError::new(
+                input,
+                number,
+                "expected a rating from 1 to 4000",
+            ));
+        }
+    }
+    if let Some(category) = categories.next() {
+        return Err(ParseError::new(
+            input,
+            category,
+            "expected only four ratings",
+        ));
+    }
+    Ok(part)
+}
@@ -0,0 +1,195 @@
+//! Day 19: Aplenty
+//!
+//! Sorts machine parts through a system of workflows, each of which accepts, rejects or forwards
+//! parts based on their ratings.
+
+use aoc_common::parse::ParseError;
+use tracing::instrument;
+
+mod common;
+mod part_1;
+mod part_2;
+
+pub use common::{parse, Condition, Instruction, Part, Rule, System, Workflow};
+
+/// Sums the ratings of every part that is accepted.
+#[instrument(skip_all)]
+pub fn part_1(system: &System) -> usize {
+    part_1::run(system)
+}
+
+/// Counts the distinct combinations of ratings, from 1 to 4000, that would be accepted.
+#[instrument(skip_all)]
+pub fn part_2(system: &System) -> usize {
+    part_2::run(&system.workflows)
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<usize, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<usize, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::cmp::Ordering;
+
+    const INPUT: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(19114));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(167409079868000));
+    }
+
+    #[test]
+    fn loops() {
+        let err = parse("in{}\n{}\n\n").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, ""));
+        let err = parse("in{x<5:a,R}\na{b}\nb{m>3:in,A}\n\n").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 7, "in"));
+        // A workflow can be reached more than once without going round in circles.
+        assert!(parse("in{x<5:a,a}\na{A}\n\n").is_ok());
+    }
+
+    #[test]
+    fn ratings() {
+        let err = parse("in{A}\n\n{x=1,m=0,a=1,s=1}").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 8, "0"));
+        assert!(parse("in{A}\n\n{x=1,m=4001,a=1,s=1}").is_err());
+        assert_eq!(run_1("in{A}\n\n{x=1,m=4000,a=1,s=1}"), Ok(4003));
+    }
+
+    /// Splits each rating's range wherever a condition on it could change, and checks one part from
+    /// each combination of the resulting ranges, since every part in it is sorted the same way.
+    fn brute_force(system: &System) -> usize {
+        let mut bounds = vec![vec![1, 4001]; 4];
+        for workflow in system.workflows.values() {
+            for Rule { condition, .. } in &workflow.rules {
+                let bound = match condition.ordering {
+                    Ordering::Less => condition.cmp,
+                    _ => condition.cmp + 1,
+                };
+                bounds[condition.category].push(bound.clamp(1, 4001));
+            }
+        }
+        let ranges = bounds
+            .into_iter()
+            .map(|mut bounds| {
+                bounds.sort();
+                bounds.dedup();
+                bounds.windows(2).map(|w| (w[0], w[1])).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut combinations = 0;
+        for &x in &ranges[0] {
+            for &m in &ranges[1] {
+                for &a in &ranges[2] {
+                    for &s in &ranges[3] {
+                        let ranges = [x, m, a, s];
+                        if accepts(system, ranges.map(|(start, _)| start)) {
+                            combinations += ranges
+                                .iter()
+                                .map(|(start, end)| end - start)
+                                .product::<usize>();
+                        }
+                    }
+                }
+            }
+        }
+        combinations
+    }
+
+    fn accepts(system: &System, part: Part) -> bool {
+        let mut workflow = &system.workflows["in"];
+        loop {
+            let instruction = workflow
+                .rules
+                .iter()
+                .find(|Rule { condition, .. }| {
+                    part[condition.category].cmp(&condition.cmp) == condition.ordering
+                })
+                .map_or(&workflow.final_instruction, |rule| &rule.instruction);
+            match instruction {
+                Instruction::Accept => return true,
+                Instruction::Reject => return false,
+                Instruction::Jump(key) => workflow = &system.workflows[key],
+            }
+        }
+    }
+
+    /// Generates the workflows of a system, in which each workflow only sends parts to workflows
+    /// after it, so that none are sent round in circles.
+    fn workflows() -> impl Strategy<Value = String> {
+        let instruction = 0..8usize;
+        let cmp = prop_oneof![0..=4001usize, prop::sample::select(&[0, 1, 4000, 4001][..])];
+        let rule = (0..4usize, any::<bool>(), cmp, instruction.clone());
+        let workflow = (prop::collection::vec(rule, 0..4), instruction);
+        prop::collection::vec(workflow, 1..5).prop_map(|workflows| {
+            let name = |idx: usize| {
+                if idx == 0 {
+                    "in".to_string()
+                } else {
+                    format!("w{idx}")
+                }
+            };
+            let count = workflows.len();
+            let instruction = |from: usize, choice: usize| match choice % (count - from + 1) {
+                0 => "A".to_string(),
+                1 => "R".to_string(),
+                choice => name(from + choice - 1),
+            };
+            workflows
+                .into_iter()
+                .enumerate()
+                .map(|(idx, (rules, final_instruction))| {
+                    let rules = rules.into_iter().map(|(category, less, cmp, choice)| {
+                        let category = ["x", "m", "a", "s"][category];
+                        let ordering = if less { '<' } else { '>' };
+                        format!("{category}{ordering}{cmp}:{}", instruction(idx, choice))
+                    });
+                    let rules = rules
+                        .chain([instruction(idx, final_instruction)])
+                        .collect::<Vec<_>>();
+                    format!("{}{{{}}}", name(idx), rules.join(","))
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+                + "\n\n"
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn part_2_matches_brute_force(workflows in workflows()) {
+            let system = parse(&workflows).unwrap();
+            prop_assert_eq!(part_2(&system), brute_force(&system));
+        }
+    }
+}
@@ -1,50 +1,6 @@
-mod common;
-mod part_1;
-mod part_2;
-
 fn main() {
-    const INPUT: &str = include_str!("../input.txt");
-    println!("{}", run_1(INPUT));
-    println!("{}", run_2(INPUT));
-}
-
-fn run_1(input: &str) -> usize {
-    part_1::run(input)
-}
-
-fn run_2(input: &str) -> usize {
-    part_2::run(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "px{a<2006:qkq,m>2090:A,rfg}
-pv{a>1716:R,A}
-lnx{m>1548:A,A}
-rfg{s<537:gd,x>2440:R,A}
-qs{s>3448:A,lnx}
-qkq{x<1416:A,crn}
-crn{x>2662:A,R}
-in{s<1351:px,qqz}
-qqz{s>2770:qs,m<1801:hdj,R}
-gd{a>3333:R,R}
-hdj{m>838:A,pv}
-
-{x=787,m=2655,a=1222,s=2876}
-{x=1679,m=44,a=2067,s=496}
-{x=2036,m=264,a=79,s=2244}
-{x=2461,m=1339,a=466,s=291}
-{x=2127,m=1623,a=2188,s=1013}";
-
-    #[test]
-    fn challenge_1() {
-        assert_eq!(run_1(INPUT), 19114);
-    }
-
-    #[test]
-    fn challenge_2() {
-        assert_eq!(run_2(INPUT), 167409079868000);
-    }
+    let input = aoc_common::input::from_args(include_str!("../input.txt"));
+    let puzzle = day_19::parse(&input).unwrap_or_else(|err| aoc_common::parse::exit(&err, &input));
+    println!("{}", day_19::part_1(&puzzle));
+    println!("{}", day_19::part_2(&puzzle));
 }
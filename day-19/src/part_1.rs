@@ -1,12 +1,12 @@
 use crate::common::Instruction::{Accept, Jump, Reject};
-use crate::common::{parse_workflow, Instruction, Rule, Workflow};
+use crate::common::{Instruction, Part, Rule, System, Workflow};
 use std::collections::HashMap;
 
-pub fn run(input: &str) -> usize {
-    let (workflows, parts) = parse_input(input);
-    parts
-        .into_iter()
-        .filter(|part| execute(part, &workflows))
+pub fn run(system: &System) -> usize {
+    system
+        .parts
+        .iter()
+        .filter(|part| execute(part, &system.workflows))
         .map(|part| part.iter().sum::<usize>())
         .sum()
 }
@@ -40,28 +40,3 @@ impl<'a> Rule<'a> {
         }
     }
 }
-
-fn parse_input(input: &str) -> (HashMap<&str, Workflow>, Vec<Part>) {
-    let (workflows, parts) = input.split_once("\n\n").unwrap();
-
-    let workflows = workflows.lines().map(parse_workflow).collect();
-    let parts = parts.lines().map(parse_part).collect();
-
-    (workflows, parts)
-}
-
-fn parse_part(part: &str) -> Part {
-    let mut categories = part[1..part.len() - 1].split(',').map(parse_category);
-    [
-        categories.next().unwrap(),
-        categories.next().unwrap(),
-        categories.next().unwrap(),
-        categories.next().unwrap(),
-    ]
-}
-
-fn parse_category(category: &str) -> usize {
-    category[2..].parse().unwrap()
-}
-
-type Part = [usize; 4];
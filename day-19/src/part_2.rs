@@ -1,12 +1,11 @@
 use crate::common::Instruction::{Accept, Jump, Reject};
-use crate::common::{parse_workflow, Instruction, Workflow};
+use crate::common::{Instruction, Workflow};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
-pub fn run(input: &str) -> usize {
-    let workflows = parse_input(input);
+pub fn run(workflows: &HashMap<&str, Workflow>) -> usize {
     let part: PossiblePart = [(1, 4000); 4];
-    execute(part, &workflows, &Jump("in"))
+    execute(part, workflows, &Jump("in"))
 }
 
 fn execute(
@@ -22,16 +21,22 @@ fn execute(
     let mut possibilitites = 0;
     for rule in &workflow.rules {
         let (category_min, category_max) = part[rule.condition.category];
-        if category_min > rule.condition.cmp {
-            match rule.condition.ordering {
-                Ordering::Less => continue, // Will always fail, so continue to the next rule without splitting.
-                _ => return possibilitites + execute(part, workflows, &rule.instruction), // Will always succeed, so execute the rule's instruction without splitting.
-            }
-        } else if category_max < rule.condition.cmp {
-            match rule.condition.ordering {
-                Ordering::Greater => continue, // Will always fail, so continue to the next rule without splitting.
-                _ => return possibilitites + execute(part, workflows, &rule.instruction), // Will always succeed, so execute the rule's instruction without splitting.
-            }
+        let (always, never) = match rule.condition.ordering {
+            Ordering::Less => (
+                category_max < rule.condition.cmp,
+                category_min >= rule.condition.cmp,
+            ),
+            _ => (
+                category_min > rule.condition.cmp,
+                category_max <= rule.condition.cmp,
+            ),
+        };
+        if never {
+            // Will always fail, so continue to the next rule without splitting.
+            continue;
+        } else if always {
+            // Will always succeed, so execute the rule's instruction without splitting.
+            return possibilitites + execute(part, workflows, &rule.instruction);
         } else {
             // Split and execute each possibility.
             let mut new_part = part;
@@ -58,9 +63,4 @@ fn possibilities(possible_part: PossiblePart) -> usize {
         .product()
 }
 
-fn parse_input(input: &str) -> HashMap<&str, Workflow> {
-    let (workflows, _) = input.split_once("\n\n").unwrap();
-    workflows.lines().map(parse_workflow).collect()
-}
-
 type PossiblePart = [(usize, usize); 4];
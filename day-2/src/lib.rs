@@ -0,0 +1,241 @@
+//! Day 2: Cube Conundrum
+//!
+//! Works out which games of cube drawing are possible with a given bag of cubes, and the fewest
+//! cubes each game could have been played with.
+
+use aoc_common::parse::{self, ParseError};
+use std::collections::{BTreeMap, HashMap};
+use tracing::instrument;
+
+/// A game of drawing cubes from a bag, and putting them back.
+pub struct Game<'a> {
+    /// The ID declared at the start of the game's line.
+    pub id: usize,
+    pub rounds: Vec<Round<'a>>,
+}
+
+/// A handful of cubes revealed during a game, as `(count, colour)` pairs.
+pub struct Round<'a> {
+    pub cubes: Vec<(usize, &'a str)>,
+}
+
+/// Parses each line of the puzzle input into a [`Game`]. Cubes may be of any colour.
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Game<'_>>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_line(input, line, None))
+        .collect()
+}
+
+/// Parses each line of the puzzle input into a [`Game`], rejecting any cube of a colour that isn't
+/// in the bag.
+pub fn parse_with<'a>(input: &'a str, bag: &Params) -> Result<Vec<Game<'a>>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_line(input, line, Some(bag)))
+        .collect()
+}
+
+/// The number of cubes of each colour in the bag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    pub cubes: BTreeMap<String, usize>,
+}
+
+/// The puzzle's bag: 12 red cubes, 13 green cubes and 14 blue cubes.
+impl Default for Params {
+    fn default() -> Self {
+        let cubes = [("red", 12), ("green", 13), ("blue", 14)];
+        Self {
+            cubes: cubes
+                .into_iter()
+                .map(|(colour, count)| (colour.to_string(), count))
+                .collect(),
+        }
+    }
+}
+
+/// Sums the IDs of the games that would have been possible with only 12 red cubes, 13 green cubes
+/// and 14 blue cubes, or returns `None` if the sum overflows.
+#[instrument(skip_all)]
+pub fn part_1(games: &[Game]) -> Option<usize> {
+    part_1_with(games, &Params::default())
+}
+
+/// Sums the IDs of the games that would have been possible with the cubes in the given bag. A game
+/// revealing a colour the bag has none of is impossible. Returns `None` if the sum overflows.
+pub fn part_1_with(games: &[Game], bag: &Params) -> Option<usize> {
+    games
+        .iter()
+        .filter(|game| is_possible(game, bag))
+        .try_fold(0usize, |sum, game| sum.checked_add(game.id))
+}
+
+/// Sums the power of the minimum set of cubes required for each game, or returns `None` if the
+/// sum overflows.
+#[instrument(skip_all)]
+pub fn part_2(games: &[Game]) -> Option<usize> {
+    games
+        .iter()
+        .try_fold(0usize, |sum, game| sum.checked_add(power(game)?))
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+impl Game<'_> {
+    /// Every handful of cubes revealed during the game, as `(count, colour)` pairs.
+    pub fn cubes(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.rounds
+            .iter()
+            .flat_map(|round| round.cubes.iter().copied())
+    }
+}
+
+fn is_possible(game: &Game, bag: &Params) -> bool {
+    game.cubes()
+        .all(|(count, colour)| bag.cubes.get(colour).is_some_and(|&max| count <= max))
+}
+
+fn power(game: &Game) -> Option<usize> {
+    let mut fewest = HashMap::<&str, usize>::new();
+    for (count, colour) in game.cubes() {
+        let max = fewest.entry(colour).or_default();
+        *max = count.max(*max);
+    }
+    fewest
+        .values()
+        .try_fold(1usize, |product, &count| product.checked_mul(count))
+}
+
+fn parse_line<'a>(
+    input: &str,
+    line: &'a str,
+    bag: Option<&Params>,
+) -> Result<Game<'a>, ParseError> {
+    let (game, rounds) = parse::split_once(input, line, ": ")?;
+    let id = parse::number(input, parse::strip_prefix(input, game, "Game ")?)?;
+    let rounds = rounds
+        .split("; ")
+        .map(|round| {
+            let cubes = round
+                .split(", ")
+                .map(|cube| {
+                    let (count, colour) = parse::split_once(input, cube, " ")?;
+                    check_colour(input, colour, bag)?;
+                    Ok((parse::number(input, count)?, colour))
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Round { cubes })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Game { id, rounds })
+}
+
+/// Checks that `colour` names a colour, and, if there's a bag, one of its colours.
+fn check_colour(input: &str, colour: &str, bag: Option<&Params>) -> Result<(), ParseError> {
+    if colour.is_empty() || colour.contains(char::is_whitespace) {
+        return Err(ParseError::new(input, colour, "expected a colour"));
+    }
+    match bag {
+        Some(bag) if !bag.cubes.contains_key(colour) => {
+            let colours = bag
+                .cubes
+                .keys()
+                .map(|colour| format!("`{colour}`"))
+                .collect::<Vec<_>>();
+            let message = match colours.split_last() {
+                None => "the bag has no cubes".to_string(),
+                Some((last, [])) => format!("expected {last}"),
+                Some((last, rest)) => format!("expected {} or {last}", rest.join(", ")),
+            };
+            Err(ParseError::new(input, colour, message))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(8)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(2286)));
+    }
+
+    #[test]
+    fn bigger_bag() {
+        let games = parse(INPUT).unwrap();
+        let mut bag = Params::default();
+        bag.cubes.insert("red".to_string(), 20);
+        assert_eq!(part_1_with(&games, &bag), Some(11));
+    }
+
+    #[test]
+    fn unknown_colour() {
+        const INPUT: &str = "Game 1: 3 blue, 4 purple";
+        let err = parse_with(INPUT, &Params::default()).err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 19, "purple"));
+        assert_eq!(err.message, "expected `blue`, `green` or `red`");
+
+        // Without a bag any colour is read, but a game revealing one the bag lacks is impossible.
+        let games = parse(INPUT).unwrap();
+        assert_eq!(part_1(&games), Some(0));
+        assert_eq!(part_2(&games), Some(12));
+        let bag = Params {
+            cubes: BTreeMap::from([("blue".to_string(), 3), ("purple".to_string(), 4)]),
+        };
+        assert_eq!(part_1_with(&games, &bag), Some(1));
+    }
+
+    #[test]
+    fn rounds() {
+        let games = parse(INPUT).unwrap();
+        let ids = games.iter().map(|game| game.id).collect::<Vec<_>>();
+        assert_eq!(ids, [1, 2, 3, 4, 5]);
+        let rounds = &games[2].rounds;
+        assert_eq!(rounds.len(), 3);
+        assert_eq!(rounds[2].cubes, [(5, "green"), (1, "red")]);
+    }
+
+    #[test]
+    fn declared_ids() {
+        let games = parse("Game 7: 1 red\nGame 3: 20 red").unwrap();
+        assert_eq!(part_1(&games), Some(7));
+        let err = parse("Game 1: 1 red\nRound 2: 1 red").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("Game x: 1 red").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 6, "x"));
+    }
+
+    #[test]
+    fn overflow() {
+        let input = "Game 1: 12 red, 00000000000009999999999999999999 red, 2 blue";
+        assert_eq!(run_2(input), Ok(None));
+        let max = usize::MAX;
+        assert_eq!(
+            run_1(&format!("Game {max}: 1 red\nGame 1: 1 red")),
+            Ok(None)
+        );
+    }
+}
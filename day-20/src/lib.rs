@@ -0,0 +1,355 @@
+//! Day 20: Pulse Propagation
+//!
+//! Simulates pulses propagating through a network of flip-flop and conjunction modules each time the
+//! button is pushed.
+
+// You could definitely solve this problem using bitwise operations.
+
+use crate::ModuleKind::{Broadcast, Conjunction, FlipFlop};
+use aoc_common::parse::{self, ParseError};
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+use tracing::{info_span, instrument};
+
+static START_MODULES: Lazy<HashMap<&str, (Vec<&str>, bool)>> =
+    Lazy::new(|| HashMap::from([("button", (vec!["broadcaster"], false))]));
+
+/// The module configuration, keyed by each module's name.
+pub type Configuration<'a> = HashMap<&'a str, Module<'a>>;
+
+/// Multiplies the number of low pulses sent by the number of high pulses sent after pushing the
+/// button 1000 times, or returns `None` if the pulses from a push never stop or the count
+/// overflows.
+#[instrument(skip_all)]
+pub fn part_1(configuration: &Configuration) -> Option<usize> {
+    part_1_with(configuration, &Params::default())
+}
+
+/// How many times to push the button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub presses: usize,
+}
+
+/// The puzzle's 1000 pushes of the button.
+impl Default for Params {
+    fn default() -> Self {
+        Self { presses: 1000 }
+    }
+}
+
+/// Multiplies the number of low pulses sent by the number of high pulses sent after pushing the
+/// button `params.presses` times, or returns `None` if the pulses from a push never stop or the
+/// count overflows.
+pub fn part_1_with(configuration: &Configuration, params: &Params) -> Option<usize> {
+    let mut modules_config = configuration.clone();
+    let mut low_signals_sent = 0usize;
+    let mut high_signals_sent = 0usize;
+    for _ in 0..params.presses {
+        let (low, high) = press(&mut modules_config)?;
+        low_signals_sent = low_signals_sent.checked_add(low)?;
+        high_signals_sent = high_signals_sent.checked_add(high)?;
+    }
+    high_signals_sent.checked_mul(low_signals_sent)
+}
+
+/// Counts the fewest button presses required to deliver a single low pulse to the `rx` module.
+///
+/// Returns `None` if nothing sends pulses to `rx`, if the pulses from a push never stop, if four of
+/// the inputs to `rx`'s sender never send it high pulses periodically, or if the count overflows.
+#[instrument(skip_all)]
+pub fn part_2(configuration: &Configuration) -> Option<usize> {
+    let mut modules_config = configuration.clone();
+    let rx_sender = *modules_config
+        .iter()
+        .find_map(|(sender, destination_module)| {
+            if destination_module.destinations.contains(&"rx") {
+                Some(sender)
+            } else {
+                None
+            }
+        })?;
+    let mut button_presses = 0;
+    let mut first_high_signals = HashMap::new();
+    let mut high_signal_periods = HashMap::new();
+    let mut pressed_states = HashMap::new();
+    let mut deadline = None;
+    loop {
+        let _press = info_span!("press").entered();
+        if deadline.is_none() {
+            let state = snapshot(&modules_config, &HashMap::new());
+            if let Some(earlier) = pressed_states.insert(state, button_presses) {
+                // The pushes repeat from here on, so any input that sends a high pulse every so
+                // often does so twice within two more rounds of them.
+                deadline = Some(button_presses + 2 * (button_presses - earlier));
+            }
+        }
+        if deadline.is_some_and(|deadline| button_presses >= deadline) {
+            return None;
+        }
+        button_presses += 1;
+        let mut modules = START_MODULES.clone();
+        let mut snapshots = HashSet::new();
+        for round in 0.. {
+            if modules.is_empty() {
+                break;
+            }
+            if round > modules_config.len()
+                && !snapshots.insert(snapshot(&modules_config, &modules))
+            {
+                return None;
+            }
+            let mut next_modules = HashMap::new();
+            for (sender, (destinations, pulse)) in modules {
+                for destination in destinations {
+                    if destination == rx_sender && pulse {
+                        if let Some(first_high_signal) =
+                            first_high_signals.insert(sender, button_presses)
+                        {
+                            let period = button_presses - first_high_signal;
+                            high_signal_periods.insert(sender, period);
+                            if high_signal_periods.len() == 4 {
+                                return high_signal_periods
+                                    .values()
+                                    .try_fold(1usize, |product, &period| {
+                                        product.checked_mul(period)
+                                    });
+                            }
+                        }
+                    }
+                    if let Some((next_destinations, next_pulse)) =
+                        send(&mut modules_config, sender, destination, pulse)
+                    {
+                        next_modules.insert(destination, (next_destinations, next_pulse));
+                    }
+                }
+            }
+            modules = next_modules;
+        }
+    }
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+/// Pushes the button once, updating the state of every module the pulses reach, and counts the
+/// low and high pulses sent. Returns `None` if the pulses never stop.
+pub fn press(modules_config: &mut Configuration) -> Option<(usize, usize)> {
+    let _press = info_span!("press").entered();
+    let mut low_signals_sent = 1;
+    let mut high_signals_sent = 0;
+    let mut modules = START_MODULES.clone();
+    let mut snapshots = HashSet::new();
+    for round in 0.. {
+        if modules.is_empty() {
+            break;
+        }
+        // Pulses that don't go round a loop of modules die out within as many rounds as there are
+        // modules, so only after that is it worth checking whether they're going round forever.
+        if round > modules_config.len() && !snapshots.insert(snapshot(modules_config, &modules)) {
+            return None;
+        }
+        let mut next_modules = HashMap::new();
+        for (sender, (destinations, pulse)) in modules {
+            for destination in destinations {
+                if let Some((next_destinations, next_pulse)) =
+                    send(modules_config, sender, destination, pulse)
+                {
+                    if next_pulse {
+                        high_signals_sent += next_destinations.len();
+                    } else {
+                        low_signals_sent += next_destinations.len();
+                    }
+                    next_modules.insert(destination, (next_destinations, next_pulse));
+                }
+            }
+        }
+        modules = next_modules;
+    }
+    Some((low_signals_sent, high_signals_sent))
+}
+
+/// The state of every module, and the pulses each module is about to send. Pulses that bring the
+/// modules back to a snapshot taken earlier in the same push go round in circles forever.
+fn snapshot<'a>(
+    modules_config: &Configuration<'a>,
+    modules: &HashMap<&'a str, (Vec<&'a str>, bool)>,
+) -> (Vec<bool>, Vec<(&'a str, bool)>) {
+    let state = modules_config
+        .values()
+        .flat_map(|module| match &module.kind {
+            Broadcast => Vec::new(),
+            FlipFlop { on } => vec![*on],
+            Conjunction { memory } => memory.values().copied().collect(),
+        })
+        .collect();
+    let mut pulses = modules
+        .iter()
+        .map(|(sender, (_, pulse))| (*sender, *pulse))
+        .collect::<Vec<_>>();
+    pulses.sort_unstable();
+    (state, pulses)
+}
+
+fn send<'a>(
+    modules_config: &mut HashMap<&'a str, Module<'a>>,
+    sender: &'a str,
+    destination: &'a str,
+    pulse: bool,
+) -> Option<(Vec<&'a str>, bool)> {
+    modules_config.get_mut(destination).and_then(|module| {
+        module
+            .receive(sender, pulse)
+            .map(|next_pulse| (module.destinations.clone(), next_pulse))
+    })
+}
+
+/// Parses the puzzle input into the module [`Configuration`].
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Configuration<'_>, ParseError> {
+    let mut destinations_to_inputs = HashMap::<&str, Vec<&str>>::new();
+    let mut modules = input
+        .lines()
+        .map(|line| {
+            let (name, destinations) = parse::split_once(input, line, " -> ")?;
+            let (name, kind) = match name.as_bytes().first() {
+                Some(b'%') => (&name[1..], ModuleKind::flip_flop()),
+                Some(b'&') => (&name[1..], ModuleKind::conjunction()),
+                _ => (name, ModuleKind::broadcast()),
+            };
+            if name.is_empty() {
+                return Err(ParseError::new(input, name, "expected a module name"));
+            }
+            let destinations = destinations.split(", ").collect();
+            for &destination in &destinations {
+                destinations_to_inputs
+                    .entry(destination)
+                    .or_default()
+                    .push(name);
+            }
+            Ok((name, Module { kind, destinations }))
+        })
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    for (destination, inputs) in destinations_to_inputs {
+        if let Some(module) = modules.get_mut(destination) {
+            if let Conjunction { memory } = &mut module.kind {
+                memory.extend(inputs.into_iter().map(|input| (input, false)));
+            }
+        }
+    }
+    Ok(modules)
+}
+
+/// A single module, and the modules it sends pulses to.
+#[derive(Clone)]
+pub struct Module<'a> {
+    pub kind: ModuleKind<'a>,
+    pub destinations: Vec<&'a str>,
+}
+
+impl<'a> Module<'a> {
+    fn receive(&mut self, sender: &'a str, pulse: bool) -> Option<bool> {
+        match &mut self.kind {
+            Broadcast => Some(pulse),
+            FlipFlop { on } => {
+                if pulse {
+                    None
+                } else {
+                    *on = !*on;
+                    Some(*on)
+                }
+            }
+            Conjunction { memory } => {
+                memory.insert(sender, pulse);
+                Some(!(pulse && memory.values().all(|pulse| *pulse)))
+            }
+        }
+    }
+}
+
+/// The type of a module, along with its state.
+#[derive(Clone)]
+pub enum ModuleKind<'a> {
+    Broadcast,
+    FlipFlop { on: bool },
+    /// A conjunction module remembers the most recent pulse (`true` is high) from each input.
+    Conjunction { memory: HashMap<&'a str, bool> },
+}
+
+impl ModuleKind<'_> {
+    fn broadcast() -> Self {
+        Broadcast
+    }
+    fn flip_flop() -> Self {
+        FlipFlop { on: false }
+    }
+    fn conjunction() -> Self {
+        Conjunction {
+            memory: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_1: &str = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    const INPUT_2: &str = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT_1), Ok(Some(32000000)));
+        assert_eq!(run_1(INPUT_2), Ok(Some(11687500)));
+    }
+
+    #[test]
+    fn fewer_presses() {
+        let configuration = parse(INPUT_1).unwrap();
+        assert_eq!(
+            part_1_with(&configuration, &Params { presses: 1 }),
+            Some(8 * 4)
+        );
+    }
+
+    #[test]
+    fn presses() {
+        let mut configuration = parse(INPUT_2).unwrap();
+        assert_eq!(press(&mut configuration), Some((4, 4)));
+        assert!(matches!(configuration["a"].kind, FlipFlop { on: true }));
+        assert_eq!(press(&mut configuration), Some((4, 2)));
+        assert!(matches!(configuration["a"].kind, FlipFlop { on: false }));
+    }
+
+    #[test]
+    fn endless_pulses() {
+        let mut configuration = parse("broadcaster -> a\n&a -> a").unwrap();
+        assert_eq!(press(&mut configuration), None);
+        assert_eq!(run_1("broadcaster -> a\n&a -> a"), Ok(None));
+        assert_eq!(run_2("broadcaster -> a\n&a -> a, rx"), Ok(None));
+    }
+
+    #[test]
+    fn no_rx() {
+        assert_eq!(run_2(""), Ok(None));
+        assert_eq!(run_2(INPUT_1), Ok(None));
+        // The flip-flop's pulses to `con` repeat every other push, but `con` has a single input.
+        assert_eq!(run_2("broadcaster -> a\n%a -> con\n&con -> rx"), Ok(None));
+    }
+}
@@ -0,0 +1,108 @@
+//! Day 21: Step Counter
+//!
+//! Counts the garden plots the elf could reach in exactly a given number of steps.
+
+use aoc_common::parse::ParseError;
+use aoc_grid::Grid;
+use std::collections::HashSet;
+use tracing::instrument;
+
+/// The map of the garden.
+pub struct Garden {
+    /// Whether each tile is a rock.
+    pub rocks: Grid<bool>,
+    /// The `(row, column)` of the starting position, `S`.
+    pub start: (usize, usize),
+}
+
+/// Parses the puzzle input into a [`Garden`].
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Garden, ParseError> {
+    let tiles = Grid::try_parse_with(input.as_bytes(), |b| match b {
+        b'.' | b'#' | b'S' => Ok(b),
+        _ => Err("expected `.`, `#` or `S`"),
+    })?;
+    let start = tiles
+        .position(|&b| b == b'S')
+        .ok_or_else(|| ParseError::end_of_input(input, "expected a starting position, `S`"))?;
+    Ok(Garden {
+        rocks: tiles.map(|&b| b == b'#'),
+        start,
+    })
+}
+
+/// Counts the garden plots reachable in exactly 64 steps.
+#[instrument(skip_all)]
+pub fn part_1(garden: &Garden) -> usize {
+    part_1_with(garden, &Params::default())
+}
+
+/// How many steps the elf takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub steps: usize,
+}
+
+/// The puzzle's 64 steps.
+impl Default for Params {
+    fn default() -> Self {
+        Self { steps: 64 }
+    }
+}
+
+/// Counts the garden plots reachable in exactly `params.steps` steps.
+pub fn part_1_with(garden: &Garden, params: &Params) -> usize {
+    reachable(garden, params.steps).len()
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<usize, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Finds the `(row, column)` of every garden plot reachable in exactly `steps` steps.
+pub fn reachable(garden: &Garden, steps: usize) -> HashSet<(usize, usize)> {
+    let Garden { rocks, start } = garden;
+    let mut positions = HashSet::from([*start]);
+    for _ in 0..steps {
+        positions = step(rocks, &positions);
+    }
+    positions
+}
+
+/// Finds every garden plot reachable in one step from any of the given positions.
+pub fn step(rocks: &Grid<bool>, positions: &HashSet<(usize, usize)>) -> HashSet<(usize, usize)> {
+    let mut new_positions = HashSet::new();
+    for &position in positions {
+        for new_position in rocks.neighbours4(position) {
+            if !rocks[new_position] {
+                new_positions.insert(new_position);
+            }
+        }
+    }
+    new_positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(reachable(&parse(INPUT).unwrap(), 6).len(), 16);
+        let garden = parse(INPUT).unwrap();
+        assert_eq!(part_1_with(&garden, &Params { steps: 6 }), 16);
+    }
+}
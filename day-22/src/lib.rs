@@ -0,0 +1,267 @@
+//! Day 22: Sand Slabs
+//!
+//! Lets a snapshot of falling bricks settle, then works out which bricks could safely be
+//! disintegrated, and how many other bricks would fall if each were.
+
+use aoc_common::parse::{self, ParseError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashMap;
+use tracing::instrument;
+
+/// Counts the bricks that could be disintegrated without any other bricks falling.
+#[instrument(skip_all)]
+pub fn part_1(bricks: &[Brick]) -> usize {
+    let mut bricks = bricks.to_vec();
+    bricks.sort_unstable_by_key(|brick| brick.start.z);
+    let (bricks, _) = fall(bricks);
+    disintegrate(&bricks)
+}
+
+/// Sums, for each brick, the number of other bricks that would fall if it were disintegrated.
+///
+/// Each brick is disintegrated on its own thread when the `parallel` feature is enabled.
+#[instrument(skip_all)]
+pub fn part_2(bricks: &[Brick]) -> usize {
+    let mut bricks = bricks.to_vec();
+    bricks.sort_unstable_by_key(|brick| brick.start.z);
+    let (bricks, _) = fall(bricks);
+    #[cfg(feature = "parallel")]
+    let indices = (0..bricks.len()).into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let indices = 0..bricks.len();
+    indices
+        .map(|i| {
+            let mut bricks = bricks.clone();
+            bricks.remove(i);
+            let (_, count) = fall(bricks);
+            count
+        })
+        .sum()
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<usize, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<usize, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+#[instrument(skip_all)]
+fn disintegrate(bricks: &[Brick]) -> usize {
+    // Keyed by height, as a single tall brick can leave most heights with no bricks at all.
+    let mut bricks_sorted_by_start = HashMap::<usize, Vec<&Brick>>::new();
+    let mut bricks_sorted_by_end = HashMap::<usize, Vec<&Brick>>::new();
+    for brick in bricks {
+        bricks_sorted_by_start
+            .entry(brick.start.z)
+            .or_default()
+            .push(brick);
+        bricks_sorted_by_end
+            .entry(brick.end.z)
+            .or_default()
+            .push(brick);
+    }
+    bricks
+        .iter()
+        .filter(|brick| can_disintegrate(brick, &bricks_sorted_by_start, &bricks_sorted_by_end))
+        .count()
+}
+
+fn can_disintegrate(
+    brick: &Brick,
+    bricks_sorted_by_start: &HashMap<usize, Vec<&Brick>>,
+    bricks_sorted_by_end: &HashMap<usize, Vec<&Brick>>,
+) -> bool {
+    // Find all the bricks this brick is supporting and check if those bricks have more than one supporting brick.
+    for supported_brick in bricks_sorted_by_start
+        .get(&(brick.end.z + 1))
+        .unwrap_or(&Vec::new())
+        .iter()
+        .filter(|other| other.is_z_aligned(brick))
+    {
+        if count_supports(supported_brick, bricks_sorted_by_end) == 1 {
+            // This brick is the only support for at least one other brick.
+            return false;
+        }
+    }
+    // All supported bricks have multiple supports, or this brick is not supporting any other bricks.
+    true
+}
+
+fn count_supports(brick: &Brick, bricks_sorted_by_end: &HashMap<usize, Vec<&Brick>>) -> usize {
+    bricks_sorted_by_end[&(brick.start.z - 1)]
+        .iter()
+        .filter(|other| other.is_z_aligned(brick))
+        .count()
+}
+
+#[instrument(skip_all)]
+fn fall(bricks: Vec<Brick>) -> (Vec<Brick>, usize) {
+    let mut fallen = Vec::<Brick>::new();
+    let mut count = 0;
+    for brick in bricks {
+        let landed = land(&fallen, &brick);
+        if landed.start.z != brick.start.z {
+            count += 1;
+        }
+        fallen.push(landed);
+    }
+    (fallen, count)
+}
+
+/// Moves the brick down until it rests on the ground or on one of the bricks that have already
+/// fallen.
+pub fn land(fallen: &[Brick], brick: &Brick) -> Brick {
+    // Find the highest fallen brick underneath this brick.
+    let to_move = if let Some(z) = fallen
+        .iter()
+        .filter(|fallen_brick| fallen_brick.is_z_aligned(brick))
+        .map(|fallen_brick| fallen_brick.end.z)
+        .max()
+    {
+        // Move this brick down onto the brick underneath.
+        brick.start.z - z - 1
+    } else {
+        brick.start.z - 1
+    };
+    let mut brick = brick.clone();
+    brick.start.z -= to_move;
+    brick.end.z -= to_move;
+    brick
+}
+
+impl Brick {
+    fn is_z_aligned(&self, other: &Brick) -> bool {
+        is_aligned((self.start.x, self.end.x), (other.start.x, other.end.x))
+            && is_aligned((self.start.y, self.end.y), (other.start.y, other.end.y))
+    }
+
+    fn overlaps(&self, other: &Brick) -> bool {
+        self.is_z_aligned(other)
+            && is_aligned((self.start.z, self.end.z), (other.start.z, other.end.z))
+    }
+}
+
+fn is_aligned(a: (usize, usize), b: (usize, usize)) -> bool {
+    a.0 <= b.1 && a.1 >= b.0
+}
+
+/// Parses each line of the puzzle input into a [`Brick`].
+///
+/// Every brick must be above the ground, with its end no lower than its start along any axis, and
+/// no two bricks may overlap.
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Brick>, ParseError> {
+    let mut bricks = Vec::<Brick>::new();
+    for line in input.lines() {
+        let (start, end) = parse::split_once(input, line, "~")?;
+        let brick = Brick::new(parse_coords(input, start)?, parse_coords(input, end)?);
+        if brick.start.x > brick.end.x || brick.start.y > brick.end.y || brick.start.z > brick.end.z
+        {
+            return Err(ParseError::new(
+                input,
+                end,
+                "expected an end no lower than the start",
+            ));
+        }
+        if bricks.iter().any(|other| other.overlaps(&brick)) {
+            return Err(ParseError::new(
+                input,
+                line,
+                "expected a brick clear of the others",
+            ));
+        }
+        bricks.push(brick);
+    }
+    Ok(bricks)
+}
+
+fn parse_coords(input: &str, coords: &str) -> Result<Coords, ParseError> {
+    let (x, rest) = parse::split_once(input, coords, ",")?;
+    let (y, z) = parse::split_once(input, rest, ",")?;
+    let coords = Coords::new(
+        parse::number(input, x)?,
+        parse::number(input, y)?,
+        parse::number(input, z)?,
+    );
+    // The ground is at a height of 0, and the top of a brick needs a height above it.
+    if !(1..usize::MAX).contains(&coords.z) {
+        let message = format!("expected a height from 1 to {}", usize::MAX - 1);
+        return Err(ParseError::new(input, z, message));
+    }
+    Ok(coords)
+}
+
+/// A brick, spanning from its `start` to its `end` coordinates inclusive.
+#[derive(Clone)]
+pub struct Brick {
+    pub start: Coords,
+    pub end: Coords,
+}
+
+impl Brick {
+    pub fn new(start: Coords, end: Coords) -> Self {
+        Self { start, end }
+    }
+}
+
+/// A position in the snapshot, where `z` is the height above the ground.
+#[derive(Clone)]
+pub struct Coords {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl Coords {
+    pub fn new(x: usize, y: usize, z: usize) -> Self {
+        Self { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(5));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(7));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(run_1(""), Ok(0));
+        let err = parse("1,1,0~1,1,1").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 5));
+        let err = parse("1,1,2~1,1,1").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 7));
+        let err = parse("1,1,1~1,1,3\n0,1,2~2,1,2").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn tall_brick() {
+        let top = usize::MAX - 1;
+        let input = format!("1,1,1~1,1,{}\n1,1,{top}~1,1,{top}", top - 1);
+        assert_eq!(run_1(&input), Ok(1));
+        assert_eq!(run_2(&input), Ok(1));
+        assert!(parse(&format!("1,1,1~1,1,{}", usize::MAX)).is_err());
+    }
+}
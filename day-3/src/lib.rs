@@ -0,0 +1,143 @@
+//! Day 3: Gear Ratios
+//!
+//! Finds the part numbers in an engine schematic, which are the numbers adjacent to a symbol, and
+//! the gears, which are the `*` symbols adjacent to exactly two part numbers.
+
+use aoc_common::parse::ParseError;
+use aoc_grid::Grid;
+use std::collections::HashSet;
+use tracing::instrument;
+
+/// An engine schematic, as a grid of digits, symbols and `.`s.
+pub type Schematic = Grid<u8>;
+
+/// Parses the puzzle input into a [`Schematic`].
+#[instrument(skip_all)]
+pub fn parse(input: &[u8]) -> Result<Schematic, ParseError> {
+    Grid::try_parse_with(input, |b| {
+        if b.is_ascii_graphic() {
+            Ok(b)
+        } else {
+            Err("expected a digit, symbol or `.`")
+        }
+    })
+}
+
+/// Sums all of the part numbers in the schematic, or returns `None` if a number or the sum
+/// overflows.
+#[instrument(skip_all)]
+pub fn part_1(schematic: &Schematic) -> Option<usize> {
+    let mut sum = 0usize;
+    for (pos, c) in schematic.iter() {
+        if is_symbol(*c) {
+            for num in read_surrounding_numbers(schematic, pos)? {
+                sum = sum.checked_add(num)?;
+            }
+        }
+    }
+    Some(sum)
+}
+
+/// Sums the gear ratios of every gear in the schematic, or returns `None` if a number, a gear
+/// ratio or the sum overflows.
+#[instrument(skip_all)]
+pub fn part_2(schematic: &Schematic) -> Option<usize> {
+    let mut sum = 0usize;
+    for (pos, c) in schematic.iter() {
+        if *c == b'*' {
+            if let [a, b] = read_surrounding_numbers(schematic, pos)?[..] {
+                sum = sum.checked_add(a.checked_mul(b)?)?;
+            }
+        }
+    }
+    Some(sum)
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &[u8]) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &[u8]) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+fn is_symbol(c: u8) -> bool {
+    !matches!(c, b'0'..=b'9' | b'.')
+}
+
+/// Reads any/all numbers surrounding `pos` in `schematic`, including diagonally, or returns `None`
+/// if one of them overflows.
+fn read_surrounding_numbers(schematic: &Schematic, pos: (usize, usize)) -> Option<Vec<usize>> {
+    // A number can touch `pos` with more than one of its digits, so remember which numbers have
+    // been read by the position of their first digit.
+    let mut starts = HashSet::new();
+    let mut numbers = Vec::new();
+    for neighbour in schematic.neighbours8(pos) {
+        if let Some(start) = find_number_start(schematic, neighbour) {
+            if starts.insert(start) {
+                numbers.push(read_number(schematic, start)?);
+            }
+        }
+    }
+    Some(numbers)
+}
+
+/// Checks if there's a digit at `pos` in `schematic`, and, if so, finds the position of the first
+/// digit of the number it belongs to.
+fn find_number_start(
+    schematic: &Schematic,
+    (row, mut col): (usize, usize),
+) -> Option<(usize, usize)> {
+    if !schematic[(row, col)].is_ascii_digit() {
+        return None;
+    }
+    while col > 0 && schematic[(row, col - 1)].is_ascii_digit() {
+        col -= 1;
+    }
+    Some((row, col))
+}
+
+/// Reads the number whose first digit is at `(row, col)` in `schematic`, or returns `None` if it
+/// overflows.
+fn read_number(schematic: &Schematic, (row, col): (usize, usize)) -> Option<usize> {
+    schematic.row(row)[col..]
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .try_fold(0usize, |num, c| {
+            num.checked_mul(10)?.checked_add((c - b'0') as usize)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u8; 109] = b"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(4361)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(467835)));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(run_1(b"*99999999999999999999"), Ok(None));
+        assert_eq!(run_2(b"9999999999*9999999999"), Ok(None));
+    }
+}
@@ -0,0 +1,139 @@
+//! Day 4: Scratchcards
+//!
+//! Scores scratchcards by how many of their numbers are winning numbers, and counts the copies of
+//! later cards that winning cards earn.
+
+use aoc_common::parse::{self, ParseError};
+use std::collections::HashSet;
+use tracing::instrument;
+
+/// A single scratchcard.
+pub struct Card {
+    pub winning_numbers: HashSet<usize>,
+    pub numbers: HashSet<usize>,
+}
+
+/// Parses each line of the puzzle input into a [`Card`].
+///
+/// No card may win copies of cards past the end of the table.
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let mut cards = Vec::with_capacity(lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        let card = read_line(input, line)?;
+        if wins(&card) >= lines.len() - i {
+            return Err(ParseError::new(
+                input,
+                line,
+                "expected no more wins than there are cards after this one",
+            ));
+        }
+        cards.push(card);
+    }
+    Ok(cards)
+}
+
+/// Sums the points each card is worth, or returns `None` if the sum overflows.
+#[instrument(skip_all)]
+pub fn part_1(cards: &[Card]) -> Option<usize> {
+    cards
+        .iter()
+        .map(wins)
+        .try_fold(0usize, |sum, wins| sum.checked_add(score(wins)?))
+}
+
+/// Counts the total number of cards held once every won copy has been processed, or returns
+/// `None` if the count overflows.
+#[instrument(skip_all)]
+pub fn part_2(cards: &[Card]) -> Option<usize> {
+    let win_counts = cards.iter().map(wins).collect::<Vec<_>>();
+    let mut card_counts = vec![1usize; win_counts.len()];
+    for (i, win_count) in win_counts.iter().enumerate() {
+        for j in 1..=*win_count {
+            card_counts[i + j] = card_counts[i + j].checked_add(card_counts[i])?;
+        }
+    }
+    card_counts
+        .iter()
+        .try_fold(0usize, |sum, &count| sum.checked_add(count))
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+fn read_line(input: &str, line: &str) -> Result<Card, ParseError> {
+    // Strip prefix.
+    let line = parse::split_once(input, line, ": ")?.1;
+    let (left, right) = parse::split_once(input, line, " | ")?;
+    Ok(Card {
+        winning_numbers: read_side(input, left)?,
+        numbers: read_side(input, right)?,
+    })
+}
+
+fn read_side(input: &str, side: &str) -> Result<HashSet<usize>, ParseError> {
+    Ok(parse::numbers(input, side)?.into_iter().collect())
+}
+
+fn wins(card: &Card) -> usize {
+    card.winning_numbers.intersection(&card.numbers).count()
+}
+
+fn score(match_count: usize) -> Option<usize> {
+    if match_count == 0 {
+        Some(0)
+    } else {
+        2usize.checked_pow(u32::try_from(match_count - 1).ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(13)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(30)));
+    }
+
+    #[test]
+    fn wins_past_the_end() {
+        let err = run_2("Card 1: 1 2 | 1\nCard 2: 1 2 | 1 2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn overflow() {
+        // Each card wins a copy of every card after it, doubling the count of each.
+        let numbers = (1..=70).map(|n| n.to_string()).collect::<Vec<_>>();
+        let input = (0..70)
+            .map(|i| {
+                let wins = numbers[..69 - i].join(" ");
+                format!("Card {}: {wins} | {wins}", i + 1)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(run_1(&input), Ok(None));
+        assert_eq!(run_2(&input), Ok(None));
+    }
+}
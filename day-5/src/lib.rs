@@ -0,0 +1,236 @@
+//! Day 5: If You Give A Seed A Fertilizer
+//!
+//! Follows seeds through the almanac's chain of category maps to find the lowest location number
+//! any of them correspond to.
+
+use aoc_common::parse::{self, ParseError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use tracing::{info_span, instrument};
+
+/// The almanac: the seeds to be planted, and the maps from each category to the next, in order.
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub maps: Vec<Map>,
+}
+
+/// Parses the puzzle input into an [`Almanac`].
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let (seeds, rest) = parse::split_once(input, input, "\n\n")?;
+    Ok(Almanac {
+        seeds: parse_seeds(input, seeds)?,
+        maps: parse_maps(input, rest)?,
+    })
+}
+
+/// Finds the lowest location number corresponding to any of the seeds.
+#[instrument(skip_all)]
+pub fn part_1(almanac: &Almanac) -> usize {
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| find_location(*seed, &almanac.maps))
+        .min()
+        .unwrap()
+}
+
+/// Finds the lowest location number corresponding to any of the seeds, where the seeds are read as
+/// pairs of range starts and lengths, or `None` if every range is empty.
+///
+/// The seeds in each range are split between threads when the `parallel` feature is enabled.
+#[instrument(skip_all)]
+pub fn part_2(almanac: &Almanac) -> Option<usize> {
+    seed_ranges(&almanac.seeds)
+        .filter_map(|(seed_range_start, seed_range_length)| {
+            let _span = info_span!("seed_range", start = seed_range_start).entered();
+            #[cfg(feature = "parallel")]
+            let offsets = (0..seed_range_length).into_par_iter();
+            #[cfg(not(feature = "parallel"))]
+            let offsets = 0..seed_range_length;
+            offsets
+                .map(|i| find_location(seed_range_start + i, &almanac.maps))
+                .min()
+        })
+        .min()
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<usize, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+fn find_location(seed: usize, maps: &[Map]) -> usize {
+    let mut current = seed;
+    for map in maps {
+        current = map.find_dest(current);
+    }
+    current
+}
+
+fn seed_ranges(seeds: &[usize]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    seeds.chunks(2).map(|pair| (pair[0], pair[1]))
+}
+
+fn parse_seeds(input: &str, seeds: &str) -> Result<Vec<usize>, ParseError> {
+    let numbers = parse::strip_prefix(input, seeds, "seeds: ")?;
+    let seeds: Vec<usize> = parse::numbers(input, numbers)?;
+    if seeds.is_empty() || !seeds.len().is_multiple_of(2) {
+        return Err(ParseError::new(input, numbers, "expected pairs of seeds"));
+    }
+    // Every seed in each range must be a number too, for part 2.
+    if seed_ranges(&seeds).any(|(start, length)| start.checked_add(length).is_none()) {
+        return Err(ParseError::new(
+            input,
+            numbers,
+            "expected seed ranges that fit in a `usize`",
+        ));
+    }
+    Ok(seeds)
+}
+
+fn parse_maps(input: &str, maps: &str) -> Result<Vec<Map>, ParseError> {
+    maps.split("\n\n")
+        .map(|map| parse_map(input, map))
+        .collect()
+}
+
+fn parse_map(input: &str, map: &str) -> Result<Map, ParseError> {
+    let ranges = map
+        .lines()
+        .skip(1)
+        .map(|range| parse_range(input, range))
+        .collect::<Result<_, _>>()?;
+    Ok(Map { ranges })
+}
+
+fn parse_range(input: &str, range: &str) -> Result<Range, ParseError> {
+    let nums: Vec<usize> = parse::numbers(input, range)?;
+    let [destination_start, source_start, length] = nums[..] else {
+        return Err(ParseError::new(input, range, "expected three numbers"));
+    };
+    if destination_start.checked_add(length).is_none() || source_start.checked_add(length).is_none()
+    {
+        return Err(ParseError::new(
+            input,
+            range,
+            "expected a range that fits in a `usize`",
+        ));
+    }
+    Ok(Range {
+        destination_start,
+        source_start,
+        length,
+    })
+}
+
+/// A map from one category of number to the next.
+pub struct Map {
+    pub ranges: Vec<Range>,
+}
+
+impl Map {
+    /// Maps `src` to its destination number. Numbers outside of every range map to themselves.
+    pub fn find_dest(&self, src: usize) -> usize {
+        for range in &self.ranges {
+            if let Some(dest) = range.find_dest(src) {
+                return dest;
+            }
+        }
+        src
+    }
+}
+
+/// A range of source numbers, and the destination numbers they map to.
+pub struct Range {
+    pub destination_start: usize,
+    pub source_start: usize,
+    pub length: usize,
+}
+
+impl Range {
+    /// Maps `src` to its destination number, if it's within this range.
+    pub fn find_dest(&self, src: usize) -> Option<usize> {
+        if src >= self.source_start && src < self.source_start + self.length {
+            Some(self.destination_start + (src - self.source_start))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(35));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(46)));
+    }
+
+    #[test]
+    fn no_seeds() {
+        let err = run_1("seeds: \n\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 8));
+        assert_eq!(err.message, "expected pairs of seeds");
+        assert!(run_1("seeds: 1 2 3\n\n").is_err());
+    }
+
+    #[test]
+    fn empty_seed_ranges() {
+        assert_eq!(run_2("seeds: 1 0\n\n"), Ok(None));
+    }
+
+    #[test]
+    fn ranges_too_big() {
+        let max = usize::MAX;
+        assert!(run_1(&format!("seeds: {max} 0\n\n")).is_ok());
+        assert!(run_1(&format!("seeds: {max} 1\n\n")).is_err());
+        assert!(run_1(&format!("seeds: 1 1\n\nmap:\n{max} 0 2")).is_err());
+        assert!(run_1(&format!("seeds: 1 1\n\nmap:\n0 {max} 2")).is_err());
+    }
+}
@@ -0,0 +1,173 @@
+//! Day 6: Wait For It
+//!
+//! I originally solved this day's challenges by hand, but have also written the solution in code,
+//! for posterity.
+//!
+//! # Solution explain:
+//!
+//! The formula for the distance a boat will travel is simple:
+//! d = s * (T - h)
+//! where d is the distance travelled, s is the speed of the boat, T is the total race time, and h
+//! is the time that the button was held for. Since the speed of the boat is identical to the time
+//! that the button was held for, s can be replaced with h and the formula can be rearranged to the
+//! following:
+//! h^2 - T * h + d = 0
+//! We know the minimum distance the boat must travel, and the total race time, so the only unknown
+//! is h. Clearly, this is a quadratic function, and, thus, can be solved via the quadratic
+//! formula.
+//! h = (T ± sqrt(T^2 - 4 * d) / 2)
+//! This will give us the minimum and maximum hold times required to win the race. From there, all
+//! we need to do is subtract the minimum from the maximum and we have calculated the total number
+//! of ways of winning the race.
+
+use aoc_common::parse::{self, ParseError};
+use tracing::instrument;
+
+/// Parses the puzzle input into the list of [`Race`]s it describes.
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+    let (times_line, distances_line) = parse::split_once(input, input, "\n")?;
+    let times = parse_line(input, times_line)?;
+    let distances = parse_line(input, distances_line)?;
+    if times.len() != distances.len() {
+        let message = format!("expected {} distances", times.len());
+        return Err(ParseError::new(input, distances_line, message));
+    }
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+/// Multiplies together the number of ways to win each race, or returns `None` if the product
+/// overflows.
+#[instrument(skip_all)]
+pub fn part_1(races: &[Race]) -> Option<u64> {
+    races
+        .iter()
+        .map(number_of_ways_to_win)
+        .try_fold(1u64, u64::checked_mul)
+}
+
+/// Counts the number of ways to win the single race formed by ignoring the spaces between the
+/// numbers, i.e. by concatenating every race's time and distance together. Returns `None` if the
+/// concatenated numbers overflow.
+#[instrument(skip_all)]
+pub fn part_2(races: &[Race]) -> Option<u64> {
+    let race = races.iter().try_fold(
+        Race {
+            time: 0,
+            distance: 0,
+        },
+        |acc, race| {
+            Some(Race {
+                time: concat(acc.time, race.time)?,
+                distance: concat(acc.distance, race.distance)?,
+            })
+        },
+    )?;
+    Some(number_of_ways_to_win(&race))
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<Option<u64>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<u64>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Vec<u64>, ParseError> {
+    parse::numbers(input, parse::split_once(input, line, ":")?.1)
+}
+
+/// Appends the digits of `b` onto the end of `a`, or returns `None` if the result overflows.
+fn concat(a: u64, b: u64) -> Option<u64> {
+    a.checked_mul(10u64.checked_pow(b.checked_ilog10().unwrap_or(0) + 1)?)?
+        .checked_add(b)
+}
+
+fn number_of_ways_to_win(race: &Race) -> u64 {
+    // Squaring the time can overflow a `u64`, but never a `u128`.
+    let (time, distance) = (u128::from(race.time), u128::from(race.distance));
+    let wins = |hold: u128| hold * (time - hold) > distance;
+    // Without two distinct roots, no hold time beats the record.
+    let discriminant = match (time * time).checked_sub(4 * distance) {
+        Some(discriminant) if discriminant > 0 => discriminant as f64,
+        _ => return 0,
+    };
+    // Floating point error can put the rounded root a few hold times out for big races, so step it
+    // to the shortest winning hold time. Holding for longer than half the race only mirrors a
+    // shorter hold, so the longest winning hold time is as far from the end.
+    let mut min = (((time as f64 - discriminant.sqrt()) / 2.0) as u128).min(time / 2);
+    while min <= time / 2 && !wins(min) {
+        min += 1;
+    }
+    if min > time / 2 {
+        return 0;
+    }
+    while min > 0 && wins(min - 1) {
+        min -= 1;
+    }
+    (time - 2 * min + 1) as u64
+}
+
+/// A single race: its total time, and the record distance that must be beaten.
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(288)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(71503)));
+    }
+
+    #[test]
+    fn overflow() {
+        let max = u64::MAX;
+        let race = Race {
+            time: max,
+            distance: 0,
+        };
+        assert_eq!(number_of_ways_to_win(&race), max - 1);
+        let input = format!("Time: {max}\nDistance: {}", max / 4);
+        assert_eq!(run_1(&input), Ok(Some(max - 1)));
+        assert_eq!(run_2(&format!("Time: 1 {max}\nDistance: 0 0")), Ok(None));
+        assert_eq!(
+            run_1(&format!("Time: {max} {max}\nDistance: 0 0")),
+            Ok(None)
+        );
+    }
+
+    /// Tries every hold time.
+    fn brute_force(race: &Race) -> u64 {
+        (0..=race.time)
+            .filter(|hold| hold * (race.time - hold) > race.distance)
+            .count() as u64
+    }
+
+    proptest! {
+        #[test]
+        fn number_of_ways_to_win_matches_brute_force(time in 0..200u64, distance in 0..12000u64) {
+            let race = Race { time, distance };
+            prop_assert_eq!(number_of_ways_to_win(&race), brute_force(&race));
+        }
+    }
+}
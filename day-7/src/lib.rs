@@ -0,0 +1,371 @@
+//! Day 7: Camel Cards
+//!
+//! Ranks hands of Camel Cards by strength and totals the winnings of each hand's bid.
+
+use aoc_common::parse::{self, ParseError};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::num::ParseIntError;
+use std::ops::Sub;
+use tracing::instrument;
+
+/// Parses each line of the puzzle input into a [`Hand`] and its bid.
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<(Hand, usize)>, ParseError> {
+    input.lines().map(|line| parse_line(input, line)).collect()
+}
+
+/// Totals the winnings of every hand, where each hand wins its bid multiplied by its rank, or
+/// returns `None` if the total overflows.
+#[instrument(skip_all)]
+pub fn part_1(hands: &[(Hand, usize)]) -> Option<usize> {
+    run(hands.iter().map(|(hand, bid)| (hand.clone(), *bid)))
+}
+
+/// Totals the winnings of every hand, treating `J` cards as jokers, or returns `None` if the total
+/// overflows.
+#[instrument(skip_all)]
+pub fn part_2(hands: &[(Hand, usize)]) -> Option<usize> {
+    run(hands
+        .iter()
+        .map(|(hand, bid)| (Hand2::from(hand.clone()), *bid)))
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+fn run<H: Ord>(hands: impl Iterator<Item = (H, usize)>) -> Option<usize> {
+    let mut hands = hands.collect::<Vec<_>>();
+    hands.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    hands
+        .into_iter()
+        .enumerate()
+        .try_fold(0usize, |total, (rank, (_hand, bid))| {
+            total.checked_add((rank + 1).checked_mul(bid)?)
+        })
+}
+
+fn parse_line(input: &str, line: &str) -> Result<(Hand, usize), ParseError> {
+    let (cards, bid) = parse::split_once(input, line, " ")?;
+    let hand =
+        Hand::try_from(cards).map_err(|err| ParseError::new(input, cards, err.to_string()))?;
+    let bid = parse::number(input, bid)?;
+    Ok((hand, bid))
+}
+
+/// A hand of five cards.
+#[derive(Debug, Clone, Eq)]
+pub struct Hand {
+    pub cards: [Card; 5],
+}
+
+/// A hand of five cards, where `J` cards are jokers rather than jacks.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Hand2 {
+    pub cards: [Card; 5],
+}
+
+/// A single card, ordered by its strength.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Card {
+    Num(u8),
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+enum Card2 {
+    J,
+    Num(u8),
+    T,
+    Q,
+    K,
+    A,
+}
+
+/// The type of a hand, ordered by its strength.
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Kind {
+    High,
+    Pair,
+    TwoPair,
+    Three,
+    FullHouse,
+    Four,
+    Five,
+}
+
+impl From<&Card> for Card2 {
+    fn from(value: &Card) -> Self {
+        match value {
+            Card::Num(num) => Card2::Num(*num),
+            Card::T => Card2::T,
+            Card::J => Card2::J,
+            Card::Q => Card2::Q,
+            Card::K => Card2::K,
+            Card::A => Card2::A,
+        }
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.kind().cmp(&other.kind()) {
+            Ordering::Equal => self.cards.cmp(&other.cards),
+            cmp => cmp,
+        }
+    }
+}
+
+impl PartialOrd<Self> for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq<Self> for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cards.eq(&other.cards)
+    }
+}
+
+impl Ord for Hand2 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.kind().cmp(&other.kind()) {
+            Ordering::Equal => self
+                .cards
+                .iter()
+                .map(Card2::from)
+                .cmp(other.cards.iter().map(Card2::from)),
+            cmp => cmp,
+        }
+    }
+}
+
+impl PartialOrd<Self> for Hand2 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hand {
+    /// Determines the type of this hand.
+    pub fn kind(&self) -> Kind {
+        let mut groups: [u8; 13] = [0; 13];
+        for card in &self.cards {
+            groups[card.value() as usize] += 1;
+        }
+        groups.sort_unstable();
+        groups.reverse();
+        kind(groups)
+    }
+}
+
+impl Hand2 {
+    /// Determines the type of this hand, using each joker as whichever card makes the strongest
+    /// hand.
+    pub fn kind(&self) -> Kind {
+        let mut groups: [u8; 13] = [0; 13];
+        let mut j_count = 0;
+        for card in &self.cards {
+            if let Card::J = card {
+                j_count += 1;
+            } else {
+                groups[card.value() as usize] += 1;
+            }
+        }
+        groups.sort_unstable();
+        groups.reverse();
+        groups[0] += j_count;
+        kind(groups)
+    }
+}
+
+fn kind(groups: [u8; 13]) -> Kind {
+    if groups[0] == 5 {
+        Kind::Five
+    } else if groups[0] == 4 {
+        Kind::Four
+    } else if groups[0] == 3 && groups[1] == 2 {
+        Kind::FullHouse
+    } else if groups[0] == 3 {
+        Kind::Three
+    } else if groups[0] == 2 && groups[1] == 2 {
+        Kind::TwoPair
+    } else if groups[0] == 2 {
+        Kind::Pair
+    } else {
+        Kind::High
+    }
+}
+
+impl Card {
+    fn value(&self) -> u8 {
+        match self {
+            Card::Num(num) => *num,
+            Card::T => 10,
+            Card::J => 11,
+            Card::Q => 12,
+            Card::K => 13,
+            Card::A => 14,
+        }
+        .sub(2)
+    }
+}
+
+impl TryFrom<&str> for Hand {
+    type Error = ParseHandError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut iter = value.bytes().map(Card::try_from);
+        let cards = [
+            iter.next().ok_or(ParseHandError::TooFewCards)??,
+            iter.next().ok_or(ParseHandError::TooFewCards)??,
+            iter.next().ok_or(ParseHandError::TooFewCards)??,
+            iter.next().ok_or(ParseHandError::TooFewCards)??,
+            iter.next().ok_or(ParseHandError::TooFewCards)??,
+        ];
+        if iter.next().is_some() {
+            return Err(ParseHandError::TooManyCards);
+        }
+        Ok(Hand { cards })
+    }
+}
+
+impl From<Hand> for Hand2 {
+    fn from(value: Hand) -> Self {
+        Hand2 { cards: value.cards }
+    }
+}
+
+impl TryFrom<u8> for Card {
+    type Error = ParseHandError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            b'2'..=b'9' => Card::Num(value - b'0'),
+            b'A' => Card::A,
+            b'K' => Card::K,
+            b'Q' => Card::Q,
+            b'J' => Card::J,
+            b'T' => Card::T,
+            _ => return Err(ParseHandError::UnrecognisedCard(value)),
+        })
+    }
+}
+
+/// An error encountered while parsing a hand and its bid.
+#[derive(Debug)]
+pub enum ParseHandError {
+    ParseInt(ParseIntError),
+    UnrecognisedCard(u8),
+    TooFewCards,
+    TooManyCards,
+}
+
+impl Display for ParseHandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseHandError::ParseInt(err) => write!(f, "invalid bid: {err}"),
+            ParseHandError::UnrecognisedCard(card) => {
+                write!(f, "unrecognised card '{}'", *card as char)
+            }
+            ParseHandError::TooFewCards => write!(f, "too few cards"),
+            ParseHandError::TooManyCards => write!(f, "too many cards"),
+        }
+    }
+}
+
+impl Error for ParseHandError {}
+
+impl From<ParseIntError> for ParseHandError {
+    fn from(value: ParseIntError) -> Self {
+        Self::ParseInt(value)
+    }
+}
+
+impl From<u8> for ParseHandError {
+    fn from(value: u8) -> Self {
+        Self::UnrecognisedCard(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(6440)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(5905)));
+    }
+
+    #[test]
+    fn overflow() {
+        let input = "3Q3JT 9999999999999999999\n58K22 9999999999999999999";
+        assert_eq!(run_1(input), Ok(None));
+        assert_eq!(run_2(input), Ok(None));
+    }
+
+    #[test]
+    fn cmp() {
+        let a = Hand::try_from("KK677").unwrap();
+        let b = Hand::try_from("KTJJT").unwrap();
+        assert!(a > b);
+    }
+
+    #[test]
+    fn malformed_hand() {
+        let err = parse("32T3K 765\nT55J1 684").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "T55J1"));
+        assert_eq!(err.message, "unrecognised card '1'");
+        let err = parse("32T3K7 765").unwrap_err();
+        assert_eq!(err.message, "too many cards");
+    }
+
+    #[test]
+    fn kind() {
+        assert_eq!(Hand::try_from("AAAAA").unwrap().kind(), Kind::Five);
+        assert_eq!(Hand::try_from("AA8AA").unwrap().kind(), Kind::Four);
+        assert_eq!(Hand::try_from("23332").unwrap().kind(), Kind::FullHouse);
+        assert_eq!(Hand::try_from("TTT98").unwrap().kind(), Kind::Three);
+        assert_eq!(Hand::try_from("23432").unwrap().kind(), Kind::TwoPair);
+        assert_eq!(Hand::try_from("A23A4").unwrap().kind(), Kind::Pair);
+        assert_eq!(Hand::try_from("23456").unwrap().kind(), Kind::High);
+    }
+
+    #[test]
+    fn cmp_2() {
+        let a = Hand2::from(Hand::try_from("TTTT2").unwrap());
+        let b = Hand2::from(Hand::try_from("JKKK2").unwrap());
+        assert_eq!(a.cmp(&b), Ordering::Greater);
+        assert!(a > b);
+    }
+
+    #[test]
+    fn kind_2() {
+        let hand = Hand2::from(Hand::try_from("QJJQ2").unwrap());
+        assert_eq!(hand.kind(), Kind::Four);
+    }
+}
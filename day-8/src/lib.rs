@@ -0,0 +1,168 @@
+//! Day 8: Haunted Wasteland
+//!
+//! Navigates a network of nodes by following a repeating list of left/right instructions.
+
+use aoc_common::parse::{self, ParseError};
+use std::collections::HashMap;
+use tracing::instrument;
+
+/// The map of the network: the instructions to follow, and each node's left and right neighbours.
+pub struct Map<'a> {
+    pub steps: Vec<Step>,
+    pub nodes: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+/// Counts the steps required to get from `AAA` to `ZZZ`, or returns `None` if there's no `AAA` or
+/// `ZZZ` can't be reached from it.
+#[instrument(skip_all)]
+pub fn part_1(map: &Map) -> Option<usize> {
+    steps_to(map, map.nodes.get("AAA")?, |node| node == "ZZZ")
+}
+
+/// Counts the steps required to get from every node ending in `A` to nodes ending in `Z`
+/// simultaneously, or returns `None` if one of them can't reach a node ending in `Z` or the count
+/// overflows.
+#[instrument(skip_all)]
+pub fn part_2(map: &Map) -> Option<usize> {
+    map.nodes
+        .iter()
+        .filter(|(key, _)| key.ends_with('A'))
+        .map(|(_, lr)| steps_to(map, lr, |node| node.ends_with('Z')))
+        .try_fold(1, |acc, steps| lcm(acc, steps?))
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+/// Counts the steps required to get from the node with the neighbours `start` to a node that
+/// `is_end`, or returns `None` if there's no such node on the way.
+fn steps_to(map: &Map, start: &(&str, &str), is_end: impl Fn(&str) -> bool) -> Option<usize> {
+    let Map { steps, nodes } = map;
+    // Once every pair of a node and a position in the steps has come up, the walk repeats itself.
+    let limit = nodes.len() * steps.len();
+    let mut current_node = start;
+    for (steps_taken, step) in steps.iter().cycle().take(limit).enumerate() {
+        let next_node = match step {
+            Step::L => current_node.0,
+            Step::R => current_node.1,
+        };
+        if is_end(next_node) {
+            return Some(steps_taken + 1);
+        }
+        current_node = &nodes[next_node];
+    }
+    None
+}
+
+fn lcm(x: usize, y: usize) -> Option<usize> {
+    (x / gcd(x, y)).checked_mul(y)
+}
+
+fn gcd(mut x: usize, mut y: usize) -> usize {
+    while y != 0 {
+        let temp = x % y;
+        x = y;
+        y = temp;
+    }
+    x
+}
+
+/// Parses the puzzle input into a [`Map`].
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Map<'_>, ParseError> {
+    let (steps, nodes) = parse::split_once(input, input, "\n\n")?;
+    if steps.is_empty() {
+        return Err(ParseError::new(input, steps, "expected a step"));
+    }
+    let steps = parse::chars(input, steps, "`L` or `R`", |c| match c {
+        'L' => Some(Step::L),
+        'R' => Some(Step::R),
+        _ => None,
+    })?;
+    let nodes = nodes
+        .lines()
+        .map(|node| parse_node(input, node))
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    for neighbour in nodes.values().flat_map(|(left, right)| [left, right]) {
+        if !nodes.contains_key(neighbour) {
+            return Err(ParseError::new(input, *neighbour, "unknown node"));
+        }
+    }
+    Ok(Map { steps, nodes })
+}
+
+fn parse_node<'a>(input: &str, node: &'a str) -> Result<(&'a str, (&'a str, &'a str)), ParseError> {
+    let (key, neighbours) = parse::split_once(input, node, " = ")?;
+    let neighbours = parse::strip_prefix(input, neighbours, "(")?;
+    let neighbours = parse::strip_suffix(input, neighbours, ")")?;
+    let (left, right) = parse::split_once(input, neighbours, ", ")?;
+    Ok((key, (left, right)))
+}
+
+/// A single left/right instruction.
+pub enum Step {
+    L,
+    R,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn challenge_1() {
+        const INPUT_1: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+        const INPUT_2: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(run_1(INPUT_1), Ok(Some(2)));
+        assert_eq!(run_1(INPUT_2), Ok(Some(6)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        const INPUT: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        assert_eq!(run_2(INPUT), Ok(Some(6)));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("LXR\n\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 2, "X"));
+        let err = parse("LR\n\nAAA = (AAA, BBB)").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 13, "BBB"));
+    }
+
+    #[test]
+    fn no_path() {
+        assert_eq!(run_1("L\n\nBBB = (BBB, BBB)"), Ok(None));
+        assert_eq!(run_1("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)"), Ok(None));
+        assert_eq!(run_2("L\n\n11A = (11A, 11Z)\n11Z = (11Z, 11Z)"), Ok(None));
+    }
+}
@@ -0,0 +1,122 @@
+//! Day 9: Mirage Maintenance
+//!
+//! Extrapolates the next and previous values of each history in an OASIS report.
+
+use aoc_common::parse::{self, ParseError};
+use tracing::instrument;
+
+/// A single history: a sequence of readings of one value over time.
+pub type History = Vec<i32>;
+
+/// Sums the next value extrapolated from each history, or returns `None` if a value overflows.
+#[instrument(skip_all)]
+pub fn part_1(histories: &[History]) -> Option<i32> {
+    run(
+        histories,
+        &|values| values.last().copied(),
+        &|next_value, last_value| next_value.checked_add(last_value),
+    )
+}
+
+/// Sums the previous value extrapolated from each history, or returns `None` if a value
+/// overflows.
+#[instrument(skip_all)]
+pub fn part_2(histories: &[History]) -> Option<i32> {
+    run(
+        histories,
+        &|values| values.first().copied(),
+        &|next_value, first_value| first_value.checked_sub(next_value),
+    )
+}
+
+/// Parses the input and solves part 1.
+pub fn run_1(input: &str) -> Result<Option<i32>, ParseError> {
+    Ok(part_1(&parse(input)?))
+}
+
+/// Parses the input and solves part 2.
+pub fn run_2(input: &str) -> Result<Option<i32>, ParseError> {
+    Ok(part_2(&parse(input)?))
+}
+
+fn run<E, O>(histories: &[History], extract: &E, op: &O) -> Option<i32>
+where
+    E: Fn(Vec<i32>) -> Option<i32>,
+    O: Fn(i32, i32) -> Option<i32>,
+{
+    histories
+        .iter()
+        .map(|history| next_value(history.clone(), extract, op))
+        .try_fold(0i32, |sum, value| sum.checked_add(value?))
+}
+
+fn next_value<E, O>(history: Vec<i32>, extract: E, op: O) -> Option<i32>
+where
+    E: Fn(Vec<i32>) -> Option<i32>,
+    O: Fn(i32, i32) -> Option<i32>,
+{
+    let mut sequences = Vec::new();
+    sequences.push(history);
+    loop {
+        let last_sequence = sequences.last().unwrap();
+        let mut next_sequence = Vec::with_capacity(last_sequence.len() - 1);
+        for idx in 0..(last_sequence.len() - 1) {
+            next_sequence.push(last_sequence[idx + 1].checked_sub(last_sequence[idx])?);
+        }
+        if next_sequence.iter().all(|val| *val == 0) {
+            break;
+        }
+        sequences.push(next_sequence);
+    }
+    let mut next_value = 0;
+    for value in sequences
+        .into_iter()
+        .map(|values| extract(values).unwrap())
+        .rev()
+    {
+        next_value = op(next_value, value)?;
+    }
+    Some(next_value)
+}
+
+/// Parses each line of the puzzle input into a [`History`].
+#[instrument(skip_all)]
+pub fn parse(input: &str) -> Result<Vec<History>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let history = parse::numbers(input, line)?;
+            if history.is_empty() {
+                return Err(ParseError::new(input, line, "expected a number"));
+            }
+            Ok(history)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn challenge_1() {
+        assert_eq!(run_1(INPUT), Ok(Some(114)));
+    }
+
+    #[test]
+    fn challenge_2() {
+        assert_eq!(run_2(INPUT), Ok(Some(2)));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(run_1("-2147483648 2147483647"), Ok(None));
+        assert_eq!(run_1("2147483647 2147483647"), Ok(Some(i32::MAX)));
+        assert_eq!(run_2("0 2147483647"), Ok(Some(-i32::MAX)));
+        assert_eq!(run_1("0 2147483647"), Ok(None));
+    }
+}
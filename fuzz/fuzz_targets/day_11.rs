@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Whatever parses must also be solvable, without panicking.
+fuzz_target!(|input: &[u8]| {
+    if let Ok(puzzle) = day_11::parse(input) {
+        let _ = day_11::part_1(&puzzle);
+        let _ = day_11::part_2(&puzzle);
+    }
+});
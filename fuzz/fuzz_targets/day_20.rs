@@ -0,0 +1,26 @@
+#![no_main]
+
+use day_20::ModuleKind;
+use libfuzzer_sys::fuzz_target;
+
+/// The most bits of state the modules are left to run with, as a push's pulses, and part 2's
+/// pushes, may each run through every state before repeating.
+const MAX_STATE_BITS: usize = 12;
+
+// Whatever parses must also be solvable, without panicking.
+fuzz_target!(|input: &str| {
+    if let Ok(puzzle) = day_20::parse(input) {
+        let bits = puzzle
+            .values()
+            .map(|module| match &module.kind {
+                ModuleKind::Broadcast => 0,
+                ModuleKind::FlipFlop { .. } => 1,
+                ModuleKind::Conjunction { memory } => memory.len(),
+            })
+            .sum::<usize>();
+        if bits <= MAX_STATE_BITS {
+            let _ = day_20::part_1(&puzzle);
+            let _ = day_20::part_2(&puzzle);
+        }
+    }
+});
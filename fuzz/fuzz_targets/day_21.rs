@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// The longest input whose garden is walked, as each step visits every plot reached so far.
+const MAX_LEN: usize = 1 << 12;
+
+// Whatever parses must also be solvable, without panicking.
+fuzz_target!(|input: &str| {
+    if let Ok(puzzle) = day_21::parse(input) {
+        if input.len() <= MAX_LEN {
+            let _ = day_21::part_1(&puzzle);
+        }
+    }
+});
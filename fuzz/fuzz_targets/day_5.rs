@@ -0,0 +1,22 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+/// The most seeds part 2 is left to check, as it checks each in turn.
+const MAX_SEEDS: usize = 1 << 16;
+
+// Whatever parses must also be solvable, without panicking.
+fuzz_target!(|input: &str| {
+    if let Ok(puzzle) = day_5::parse(input) {
+        let _ = day_5::part_1(&puzzle);
+        let seeds = puzzle
+            .seeds
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .try_fold(0usize, |seeds, &length| seeds.checked_add(length));
+        if seeds.is_some_and(|seeds| seeds <= MAX_SEEDS) {
+            let _ = day_5::part_2(&puzzle);
+        }
+    }
+});